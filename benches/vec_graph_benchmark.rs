@@ -1,5 +1,7 @@
-use aoc_helper::graph::{vec_graph::VecGraph, Graph};
-use criterion::{Criterion, criterion_group, criterion_main, black_box};
+use aoc_helper::graph::{vec_graph::VecGraph, Graph, NodeIndex};
+use std::{collections::HashSet, hint::black_box};
+
+use criterion::{Criterion, criterion_group, criterion_main};
 
 
 
@@ -46,5 +48,81 @@ pub fn edge_add(c: &mut Criterion) {
     });
 }
 
-criterion_group!(benches, node_add, edge_add);
+/// Build an undirected 5x5 grid graph, where the weight of each edge depends on its position, and return it with its opposite corners.
+fn weighted_grid() -> (VecGraph<usize, usize>, NodeIndex, NodeIndex) {
+    const SIZE: usize = 5;
+
+    let mut graph = VecGraph::default();
+    let nodes = (0..SIZE * SIZE).map(|i| graph.push_node(i)).collect::<Vec<_>>();
+
+    for row in 0..SIZE {
+        for col in 0..SIZE {
+            let node = nodes[row * SIZE + col];
+            let weight = (row * 3 + col * 7) % 5 + 1;
+
+            if col + 1 < SIZE {
+                graph.add_weighted_edge(node, nodes[row * SIZE + col + 1], weight);
+                graph.add_weighted_edge(nodes[row * SIZE + col + 1], node, weight);
+            }
+            if row + 1 < SIZE {
+                graph.add_weighted_edge(node, nodes[(row + 1) * SIZE + col], weight);
+                graph.add_weighted_edge(nodes[(row + 1) * SIZE + col], node, weight);
+            }
+        }
+    }
+
+    (graph, nodes[0], nodes[SIZE * SIZE - 1])
+}
+
+/// The same search as `VecGraph::longest_simple_path`, but with a `HashSet` as the visited set.
+fn longest_simple_path_hash_set(
+    graph: &VecGraph<usize, usize>,
+    current: NodeIndex,
+    target: NodeIndex,
+    visited: &mut HashSet<NodeIndex>,
+) -> Option<usize> {
+    if current == target {
+        return Some(0);
+    }
+
+    let mut longest = None;
+
+    for (next, weight) in graph.weighted_successors(current) {
+        if !visited.insert(next) {
+            continue;
+        }
+
+        if let Some(rest) = longest_simple_path_hash_set(graph, next, target, visited) {
+            longest = longest.max(Some(rest + weight));
+        }
+        visited.remove(&next);
+    }
+
+    longest
+}
+
+pub fn longest_simple_path(c: &mut Criterion) {
+    let (graph, start, target) = weighted_grid();
+
+    assert_eq!(
+        graph.longest_simple_path(start, target, |&w| w),
+        longest_simple_path_hash_set(&graph, start, target, &mut HashSet::from([start]))
+    );
+
+    let mut group = c.benchmark_group("longest_simple_path");
+
+    group.bench_function("hash_set", |b| {
+        b.iter(|| {
+            longest_simple_path_hash_set(&graph, start, target, &mut HashSet::from([start]))
+        });
+    });
+
+    group.bench_function("node_bit_set", |b| {
+        b.iter(|| graph.longest_simple_path(black_box(start), black_box(target), |&w| w));
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, node_add, edge_add, longest_simple_path);
 criterion_main!(benches);
\ No newline at end of file
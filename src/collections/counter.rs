@@ -0,0 +1,250 @@
+use std::{
+    cmp::Reverse,
+    collections::HashMap,
+    hash::Hash,
+    ops::{AddAssign, SubAssign},
+};
+
+/// A multiset that keeps track of how many times each key was added.
+/// Useful for simulations that track populations, where the order of individual elements does not matter (e.g. AoC 2021 Day 6).
+///
+/// # Example:
+///
+/// ```
+/// use aoc_helper::collections::counter::Counter;
+///
+/// let mut counter = Counter::new();
+///
+/// counter.add('a');
+/// counter.add('b');
+/// counter.add_n('a', 2);
+///
+/// assert_eq!(counter.get(&'a'), 3);
+/// assert_eq!(counter.get(&'b'), 1);
+/// assert_eq!(counter.get(&'c'), 0);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Counter<K: Eq + Hash> {
+    counts: HashMap<K, usize>,
+}
+
+impl<K: Eq + Hash> Counter<K> {
+    /// Creates a new, empty [`Counter<K>`].
+    pub fn new() -> Counter<K> {
+        Counter {
+            counts: HashMap::new(),
+        }
+    }
+
+    /// Increase the count of `key` by one.
+    pub fn add(&mut self, key: K) {
+        self.add_n(key, 1);
+    }
+
+    /// Increase the count of `key` by `n`.
+    pub fn add_n(&mut self, key: K, n: usize) {
+        if n == 0 {
+            return;
+        }
+
+        *self.counts.entry(key).or_insert(0) += n;
+    }
+
+    /// Return how many times `key` was added. Returns 0 for keys that were never added.
+    pub fn get(&self, key: &K) -> usize {
+        self.counts.get(key).copied().unwrap_or(0)
+    }
+
+    /// Return the number of distinct keys in this [`Counter<K>`].
+    pub fn len(&self) -> usize {
+        self.counts.len()
+    }
+
+    /// Returns `true` if no keys were added to this [`Counter<K>`].
+    pub fn is_empty(&self) -> bool {
+        self.counts.is_empty()
+    }
+
+    /// Return the sum of the counts of all keys.
+    pub fn total(&self) -> usize {
+        self.counts.values().sum()
+    }
+
+    /// Iterate over the keys and their counts, in arbitrary order.
+    pub fn iter(&self) -> impl Iterator<Item = (&K, usize)> {
+        self.counts.iter().map(|(k, &c)| (k, c))
+    }
+
+    /// Return the `n` keys with the highest counts, in descending order of count.
+    /// Keys with equal counts are returned in arbitrary order.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use aoc_helper::collections::counter::Counter;
+    ///
+    /// let counter: Counter<char> = "abracadabra".chars().collect();
+    ///
+    /// assert_eq!(counter.most_common(1), vec![(&'a', 5)]);
+    /// assert_eq!(counter.most_common(3).len(), 3);
+    /// ```
+    pub fn most_common(&self, n: usize) -> Vec<(&K, usize)> {
+        let mut counts = self.iter().collect::<Vec<_>>();
+        counts.sort_by_key(|(_, count)| Reverse(*count));
+        counts.truncate(n);
+
+        counts
+    }
+}
+
+impl<K: Eq + Hash> Default for Counter<K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Eq + Hash> FromIterator<K> for Counter<K> {
+    fn from_iter<I: IntoIterator<Item = K>>(iter: I) -> Self {
+        let mut counter = Counter::new();
+        iter.into_iter().for_each(|k| counter.add(k));
+
+        counter
+    }
+}
+
+/// Merge two [`Counter`]s by adding up the counts of each key.
+/// In a module that imports [`std::ops::Add`], `counter.add(key)` resolves to [`Add::add`](std::ops::Add::add), so use `Counter::add(&mut counter, key)` or [`Counter::add_n`] there.
+impl<K: Eq + Hash> std::ops::Add for Counter<K> {
+    type Output = Counter<K>;
+
+    fn add(mut self, rhs: Self) -> Self::Output {
+        self += rhs;
+        self
+    }
+}
+
+/// Subtract the counts in `rhs` from the counts in this [`Counter`], as with `-=`.
+impl<K: Eq + Hash> std::ops::Sub for Counter<K> {
+    type Output = Counter<K>;
+
+    fn sub(mut self, rhs: Self) -> Self::Output {
+        self -= rhs;
+        self
+    }
+}
+
+/// Merge `rhs` into this [`Counter`] by adding up the counts of each key.
+impl<K: Eq + Hash> AddAssign for Counter<K> {
+    fn add_assign(&mut self, rhs: Self) {
+        for (key, count) in rhs.counts {
+            self.add_n(key, count);
+        }
+    }
+}
+
+/// Subtract the counts in `rhs` from the counts in this [`Counter`]. Keys whose count would drop to zero or below are removed.
+impl<K: Eq + Hash> SubAssign for Counter<K> {
+    fn sub_assign(&mut self, rhs: Self) {
+        for (key, count) in rhs.counts {
+            if let Some(current) = self.counts.get_mut(&key) {
+                if *current > count {
+                    *current -= count;
+                } else {
+                    self.counts.remove(&key);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn add_and_get_works() {
+        let mut counter = Counter::new();
+
+        counter.add("a");
+        counter.add("b");
+        counter.add("a");
+        counter.add_n("c", 5);
+        counter.add_n("d", 0);
+
+        assert_eq!(counter.get(&"a"), 2);
+        assert_eq!(counter.get(&"b"), 1);
+        assert_eq!(counter.get(&"c"), 5);
+        assert_eq!(counter.get(&"d"), 0);
+        assert_eq!(counter.len(), 3);
+        assert_eq!(counter.total(), 8);
+    }
+
+    #[test]
+    fn most_common_works() {
+        let counter: Counter<char> = "aaaabbbccd".chars().collect();
+
+        assert_eq!(counter.most_common(2), vec![(&'a', 4), (&'b', 3)]);
+        assert_eq!(counter.most_common(10).len(), 4);
+        assert!(counter.most_common(0).is_empty());
+    }
+
+    #[test]
+    fn add_assign_and_sub_assign_merge_counts() {
+        let first: Counter<char> = "aab".chars().collect();
+        let second: Counter<char> = "abc".chars().collect();
+
+        let mut sum = first.clone();
+        sum += second.clone();
+        assert_eq!(sum.get(&'a'), 3);
+        assert_eq!(sum.get(&'b'), 2);
+        assert_eq!(sum.get(&'c'), 1);
+
+        let mut difference = first;
+        difference -= second;
+        assert_eq!(difference.get(&'a'), 1);
+        assert_eq!(difference.get(&'b'), 0);
+        assert_eq!(difference.get(&'c'), 0);
+        assert_eq!(difference.len(), 1);
+    }
+
+    #[test]
+    fn add_and_sub_merge_counts() {
+        let first: Counter<char> = "aab".chars().collect();
+        let second: Counter<char> = "abc".chars().collect();
+
+        let sum = first.clone() + second.clone();
+        assert_eq!(sum, "aaabbc".chars().collect());
+
+        let difference = first - second;
+        assert_eq!(difference, "a".chars().collect());
+
+        assert!((Counter::<char>::new() + Counter::new()).is_empty());
+    }
+
+    #[test]
+    fn can_simulate_lanternfish() {
+        // Example from AoC 2021 Day 6
+        let mut fish: Counter<u8> = [3, 4, 3, 1, 2].into_iter().collect();
+
+        for day in 1..=80 {
+            let mut next = Counter::new();
+
+            for (&timer, count) in fish.iter() {
+                if timer == 0 {
+                    next.add_n(6, count);
+                    next.add_n(8, count);
+                } else {
+                    next.add_n(timer - 1, count);
+                }
+            }
+
+            fish = next;
+
+            if day == 18 {
+                assert_eq!(fish.total(), 26);
+            }
+        }
+
+        assert_eq!(fish.total(), 5934);
+    }
+}
@@ -0,0 +1,166 @@
+/// A disjoint-set (union-find) data structure over the elements `0..n`.
+/// Keeps track of a partition of the elements into disjoint sets, and supports merging sets and checking which set an element belongs to in nearly constant time.
+///
+/// # Example:
+///
+/// ```
+/// use aoc_helper::collections::disjoint_set::DisjointSet;
+///
+/// let mut set = DisjointSet::new(4);
+///
+/// set.union(0, 1);
+/// set.union(2, 3);
+///
+/// assert_eq!(set.find(0), set.find(1));
+/// assert_ne!(set.find(1), set.find(2));
+/// assert_eq!(set.count_sets(), 2);
+/// ```
+#[derive(Debug, Clone)]
+pub struct DisjointSet {
+    parents: Vec<usize>,
+    ranks: Vec<usize>,
+    set_count: usize,
+}
+
+impl DisjointSet {
+    /// Creates a new [`DisjointSet`] with `n` elements, where each element is in its own set.
+    pub fn new(n: usize) -> DisjointSet {
+        DisjointSet {
+            parents: (0..n).collect(),
+            ranks: vec![0; n],
+            set_count: n,
+        }
+    }
+
+    /// Return the representative element of the set that contains `x`.
+    /// Two elements are in the same set if and only if they have the same representative.
+    /// Compresses the path from `x` to the representative, to speed up later queries.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `x` is not a valid element.
+    pub fn find(&mut self, x: usize) -> usize {
+        let mut root = x;
+        while self.parents[root] != root {
+            root = self.parents[root];
+        }
+
+        // Path compression: point every element on the path directly at the root
+        let mut current = x;
+        while self.parents[current] != root {
+            let next = self.parents[current];
+            self.parents[current] = root;
+            current = next;
+        }
+
+        root
+    }
+
+    /// Merge the sets that contain `a` and `b`, using union by rank.
+    /// Returns `true` if the sets were merged, or `false` if `a` and `b` were already in the same set.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either `a` or `b` is not a valid element.
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let root_a = self.find(a);
+        let root_b = self.find(b);
+
+        if root_a == root_b {
+            return false;
+        }
+
+        match self.ranks[root_a].cmp(&self.ranks[root_b]) {
+            std::cmp::Ordering::Less => self.parents[root_a] = root_b,
+            std::cmp::Ordering::Greater => self.parents[root_b] = root_a,
+            std::cmp::Ordering::Equal => {
+                self.parents[root_b] = root_a;
+                self.ranks[root_a] += 1;
+            }
+        }
+
+        self.set_count -= 1;
+        true
+    }
+
+    /// Returns the number of disjoint sets.
+    pub fn count_sets(&self) -> usize {
+        self.set_count
+    }
+
+    /// Returns the number of elements.
+    pub fn len(&self) -> usize {
+        self.parents.len()
+    }
+
+    /// Returns `true` if there are no elements.
+    pub fn is_empty(&self) -> bool {
+        self.parents.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn new_set_has_singletons() {
+        let mut set = DisjointSet::new(3);
+
+        assert_eq!(set.count_sets(), 3);
+        assert_eq!(set.len(), 3);
+
+        for i in 0..3 {
+            assert_eq!(set.find(i), i);
+        }
+    }
+
+    #[test]
+    fn union_merges_sets() {
+        let mut set = DisjointSet::new(6);
+
+        assert!(set.union(0, 1));
+        assert!(set.union(1, 2));
+        assert!(set.union(3, 4));
+        assert!(!set.union(0, 2));
+
+        assert_eq!(set.count_sets(), 3);
+        assert_eq!(set.find(0), set.find(2));
+        assert_eq!(set.find(3), set.find(4));
+        assert_ne!(set.find(2), set.find(3));
+        assert_ne!(set.find(4), set.find(5));
+
+        assert!(set.union(2, 4));
+        assert_eq!(set.count_sets(), 2);
+        assert_eq!(set.find(0), set.find(3));
+    }
+
+    #[test]
+    fn can_count_constellations() {
+        // Example from AoC 2018 Day 25
+        let points: [[i32; 4]; 8] = [
+            [0, 0, 0, 0],
+            [3, 0, 0, 0],
+            [0, 3, 0, 0],
+            [0, 0, 3, 0],
+            [0, 0, 0, 3],
+            [0, 0, 0, 6],
+            [9, 0, 0, 0],
+            [12, 0, 0, 0],
+        ];
+
+        let mut set = DisjointSet::new(points.len());
+
+        for i in 0..points.len() {
+            for j in (i + 1)..points.len() {
+                let distance: i32 = (0..4).map(|k| (points[i][k] - points[j][k]).abs()).sum();
+
+                if distance <= 3 {
+                    set.union(i, j);
+                }
+            }
+        }
+
+        assert_eq!(set.count_sets(), 2);
+    }
+}
@@ -0,0 +1,3 @@
+pub mod counter;
+pub mod disjoint_set;
+pub mod rle;
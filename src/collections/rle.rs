@@ -0,0 +1,82 @@
+/// Run-length encode `items`: every run of consecutive equal items is replaced by the length of the run, and the item.
+/// Useful for look-and-say sequences (e.g. AoC 2015 Day 10).
+///
+/// # Example:
+///
+/// ```
+/// use aoc_helper::collections::rle::rle_encode;
+///
+/// let encoded = rle_encode(&[1, 1, 2, 1]);
+///
+/// assert_eq!(encoded, vec![(2, 1), (1, 2), (1, 1)]);
+/// ```
+pub fn rle_encode<T: PartialEq + Clone>(items: &[T]) -> Vec<(usize, T)> {
+    let mut runs: Vec<(usize, T)> = Vec::new();
+
+    for item in items {
+        match runs.last_mut() {
+            Some((count, last)) if last == item => *count += 1,
+            _ => runs.push((1, item.clone())),
+        }
+    }
+
+    runs
+}
+
+/// Decode a run-length encoded sequence, as returned by [`rle_encode`], by repeating each item as many times as the length of its run.
+///
+/// # Example:
+///
+/// ```
+/// use aoc_helper::collections::rle::rle_decode;
+///
+/// let decoded = rle_decode(&[(2, 'a'), (1, 'b'), (3, 'c')]);
+///
+/// assert_eq!(decoded, vec!['a', 'a', 'b', 'c', 'c', 'c']);
+/// ```
+pub fn rle_decode<T: Clone>(runs: &[(usize, T)]) -> Vec<T> {
+    runs.iter()
+        .flat_map(|(count, item)| std::iter::repeat_n(item.clone(), *count))
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn rle_round_trip() {
+        let items = "aaabccddddae".chars().collect::<Vec<_>>();
+
+        let encoded = rle_encode(&items);
+        assert_eq!(
+            encoded,
+            vec![(3, 'a'), (1, 'b'), (2, 'c'), (4, 'd'), (1, 'a'), (1, 'e')]
+        );
+        assert_eq!(rle_decode(&encoded), items);
+
+        assert!(rle_encode::<u8>(&[]).is_empty());
+        assert!(rle_decode::<u8>(&[]).is_empty());
+    }
+
+    #[test]
+    fn rle_look_and_say() {
+        let look_and_say = |digits: &[u32]| {
+            rle_encode(digits)
+                .into_iter()
+                .flat_map(|(count, digit)| [count as u32, digit])
+                .collect::<Vec<_>>()
+        };
+
+        let mut digits = vec![1];
+        for expected in [
+            vec![1, 1],
+            vec![2, 1],
+            vec![1, 2, 1, 1],
+            vec![1, 1, 1, 2, 2, 1],
+        ] {
+            digits = look_and_say(&digits);
+            assert_eq!(digits, expected);
+        }
+    }
+}
@@ -1,3 +1,5 @@
+use std::fmt::Display;
+
 use super::{Direction, relative_direction::RelativeDirection};
 
 /// Four main directions: North, East, South, West.
@@ -21,20 +23,45 @@ impl CardinalDirection {
             CardinalDirection::West => RelativeDirection::Left,
         }
     }
+
+    /// Return the name of [`self`] as a word (e.g. "North"). This is also what the [`Display`] implementation writes.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use aoc_helper::direction::cardinal_direction::CardinalDirection;
+    ///
+    /// assert_eq!("North", CardinalDirection::North.to_word());
+    /// assert_eq!("North", CardinalDirection::North.to_string());
+    /// ```
+    pub fn to_word(&self) -> &'static str {
+        match self {
+            CardinalDirection::North => "North",
+            CardinalDirection::East => "East",
+            CardinalDirection::South => "South",
+            CardinalDirection::West => "West",
+        }
+    }
+}
+
+impl Display for CardinalDirection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_word())
+    }
 }
 
 // Associated functions
 impl Direction for CardinalDirection {
-    /// Return the horizontal directions (West and East) as an array:
+    /// Return the horizontal directions (West and East):
     /// ```
     /// use crate::aoc_helper::direction::Direction;
     /// use aoc_helper::direction::cardinal_direction::CardinalDirection;
     /// 
-    ///  let horizontal_directions = [CardinalDirection::West, CardinalDirection::East];
+    ///  let horizontal_directions = vec![CardinalDirection::West, CardinalDirection::East];
     ///  assert_eq!(horizontal_directions, Direction::get_horizontal());
     /// ```
-     fn get_horizontal() -> [CardinalDirection; 2] {
-        [CardinalDirection::West, CardinalDirection::East]
+     fn get_horizontal() -> Vec<CardinalDirection> {
+        vec![CardinalDirection::West, CardinalDirection::East]
     }
 
     /// Get a direction that will correspond to the given offset in a 2D grid. The offset should be in the format (row_offset, col_offset). The offset values should be one of -1, 0, or 1.
@@ -75,16 +102,16 @@ impl Direction for CardinalDirection {
         }
     }
 
-    /// Return the vertical directions (North and South) as an array:
+    /// Return the vertical directions (North and South):
     /// ```
     /// use crate::aoc_helper::direction::Direction;
     /// use aoc_helper::direction::cardinal_direction::CardinalDirection;
     /// 
-    /// let vertical_directions = [CardinalDirection::North, CardinalDirection::South];
+    /// let vertical_directions = vec![CardinalDirection::North, CardinalDirection::South];
     /// assert_eq!(vertical_directions, Direction::get_vertical());    
     /// ```
-     fn get_vertical() -> [CardinalDirection; 2] {
-        [CardinalDirection::North, CardinalDirection::South]
+     fn get_vertical() -> Vec<CardinalDirection> {
+        vec![CardinalDirection::North, CardinalDirection::South]
     }
 
     /// Iterate over all [`CardinalDirection`] variants. The iterator starts at [`CardinalDirection::North`], and moves clockwise.
@@ -97,8 +124,8 @@ impl Direction for CardinalDirection {
     /// let expected = vec![CardinalDirection::North, CardinalDirection::East, CardinalDirection::South, CardinalDirection::West];
     /// assert_eq!(expected, CardinalDirection::all().into_iter().collect::<Vec<_>>());
     /// ```
-     fn all() -> [CardinalDirection; 4] {
-        [CardinalDirection::North, CardinalDirection::East, CardinalDirection::South, CardinalDirection::West]
+     fn all() -> Vec<CardinalDirection> {
+        vec![CardinalDirection::North, CardinalDirection::East, CardinalDirection::South, CardinalDirection::West]
     }
 
      /// Returns [`CardinalDirection`] that is opposite of this [`CardinalDirection`] ([`CardinalDirection::West`] <-> [`CardinalDirection::East`] and [`CardinalDirection::North`] <-> [`CardinalDirection::South`]). 
@@ -225,13 +252,13 @@ pub(crate) mod test {
 
     #[test]
     fn horizontal_works() {
-        let horizontal_directions = [CardinalDirection::West, CardinalDirection::East];
+        let horizontal_directions = vec![CardinalDirection::West, CardinalDirection::East];
         assert_eq!(horizontal_directions, CardinalDirection::get_horizontal());
     }
 
     #[test]
     fn vertical_works() {
-        let vertical_directions = [CardinalDirection::North, CardinalDirection::South];
+        let vertical_directions = vec![CardinalDirection::North, CardinalDirection::South];
         assert_eq!(vertical_directions, CardinalDirection::get_vertical());
     }
 
@@ -330,4 +357,21 @@ pub(crate) mod test {
         let west = CardinalDirection::West;
         assert_eq!(CardinalDirection::South, west.get_left());
     }
-}
+
+    #[test]
+    fn to_word_works() {
+        assert_eq!("North", CardinalDirection::North.to_word());
+        assert_eq!("East", CardinalDirection::East.to_word());
+        assert_eq!("South", CardinalDirection::South.to_word());
+        assert_eq!("West", CardinalDirection::West.to_word());
+
+        let words = CardinalDirection::all().iter().map(|d| d.to_relative().to_word()).collect::<Vec<_>>();
+        assert_eq!(vec!["Up", "Right", "Down", "Left"], words);
+    }
+
+    #[test]
+    fn display_writes_word() {
+        assert_eq!("North", format!("{}", CardinalDirection::North));
+        assert_eq!("Left", format!("{}", CardinalDirection::West.to_relative()));
+    }
+}
\ No newline at end of file
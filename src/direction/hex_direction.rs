@@ -0,0 +1,98 @@
+/// The six directions of a hexagonal grid with flat-topped hexes (e.g. "n", "ne", "se", "s", "sw", "nw").
+/// Unlike the square grid directions, these do not implement [`super::Direction`]: a hex grid has six-fold symmetry, so a single turn is 60 degrees, not 90.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum HexDirection {
+    North,
+    NorthEast,
+    SouthEast,
+    South,
+    SouthWest,
+    NorthWest,
+}
+
+impl HexDirection {
+    /// Iterate over all [`HexDirection`] variants. The iterator starts at [`HexDirection::North`], and moves clockwise.
+    pub fn all() -> Vec<HexDirection> {
+        vec![
+            HexDirection::North,
+            HexDirection::NorthEast,
+            HexDirection::SouthEast,
+            HexDirection::South,
+            HexDirection::SouthWest,
+            HexDirection::NorthWest,
+        ]
+    }
+
+    /// Returns the [`HexDirection`] that is opposite of this [`HexDirection`] (e.g. [`HexDirection::NorthEast`] <-> [`HexDirection::SouthWest`]).
+    pub fn get_opposite(&self) -> HexDirection {
+        self.rotate_60(3)
+    }
+
+    /// Returns [`self`] rotated clockwise by 'steps' * 60 degrees. Negative values rotate counterclockwise, and the rotation wraps around, so rotating by 6 steps returns [`self`].
+    ///
+    /// # Examples:
+    /// ```
+    /// use aoc_helper::direction::hex_direction::HexDirection;
+    ///
+    /// assert_eq!(HexDirection::SouthEast, HexDirection::North.rotate_60(2));
+    /// assert_eq!(HexDirection::NorthWest, HexDirection::North.rotate_60(-1));
+    /// assert_eq!(HexDirection::North, HexDirection::North.rotate_60(6));
+    /// ```
+    pub fn rotate_60(&self, steps: i32) -> HexDirection {
+        let index = (*self as i32 + steps.rem_euclid(6)).rem_euclid(6) as usize;
+        HexDirection::all()[index]
+    }
+
+    /// Get the axial (q, r) offset that corresponds to this [`HexDirection`], where 'q' grows towards the east and 'r' grows towards the south.
+    /// The third cube coordinate can be derived as `s = -q - r`.
+    ///
+    /// # Examples:
+    /// ```
+    /// use aoc_helper::direction::hex_direction::HexDirection;
+    ///
+    /// assert_eq!((0, -1), HexDirection::North.get_axial_offset());
+    /// assert_eq!((1, 0), HexDirection::SouthEast.get_axial_offset());
+    /// ```
+    pub fn get_axial_offset(&self) -> (i8, i8) {
+        match self {
+            HexDirection::North => (0, -1),
+            HexDirection::NorthEast => (1, -1),
+            HexDirection::SouthEast => (1, 0),
+            HexDirection::South => (0, 1),
+            HexDirection::SouthWest => (-1, 1),
+            HexDirection::NorthWest => (-1, 0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn rotate_60_by_three_is_opposite() {
+        for direction in HexDirection::all() {
+            let opposite = direction.rotate_60(3);
+            let (q, r) = direction.get_axial_offset();
+
+            assert_eq!(opposite, direction.get_opposite());
+            assert_eq!((-q, -r), opposite.get_axial_offset());
+        }
+    }
+
+    #[test]
+    fn rotate_60_wraps() {
+        for direction in HexDirection::all() {
+            assert_eq!(direction, direction.rotate_60(6));
+            assert_eq!(direction, direction.rotate_60(-12));
+            assert_eq!(direction.rotate_60(5), direction.rotate_60(-1));
+            assert_eq!(direction, direction.rotate_60(2).rotate_60(-2));
+        }
+
+        assert_eq!(HexDirection::NorthWest, HexDirection::North.rotate_60(-7));
+
+        // i32::MAX is 1 (mod 6) and i32::MIN is 4 (mod 6)
+        assert_eq!(HexDirection::North, HexDirection::NorthWest.rotate_60(i32::MAX));
+        assert_eq!(HexDirection::SouthWest, HexDirection::North.rotate_60(i32::MIN));
+    }
+}
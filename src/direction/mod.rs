@@ -1,15 +1,15 @@
 
 /// Trait that can be implemented by types that indicate direction (e.g. North, East, Up, Right, etc.).
 pub trait Direction {
-    /// Return the horizontal directions (e.g West and East) as an array:
+    /// Return the horizontal directions (e.g West and East):
     /// ```
     /// use crate::aoc_helper::direction::Direction;
     /// use aoc_helper::direction::cardinal_direction::CardinalDirection;
     /// 
-    ///  let horizontal_directions = [CardinalDirection::West, CardinalDirection::East];
+    ///  let horizontal_directions = vec![CardinalDirection::West, CardinalDirection::East];
     ///  assert_eq!(horizontal_directions, Direction::get_horizontal());
     /// ```
-     fn get_horizontal() -> [Self; 2] where Self: Sized;
+     fn get_horizontal() -> Vec<Self> where Self: Sized;
 
     /// Get a direction that will correspond to the given offset in a 2D grid. The offset should be in the format (row_offset, col_offset). The offset values should be one of -1, 0, or 1.
     /// Note that (0, 0) is not a valid offset value, as that represents the current location.
@@ -40,17 +40,17 @@ pub trait Direction {
     /// ``` 
      fn from_offset(offset: &(i8, i8)) -> Self where Self:Sized;
 
-    /// Return the vertical directions (e.g. North and South) as an array:
+    /// Return the vertical directions (e.g. North and South):
     /// ```
     /// use crate::aoc_helper::direction::Direction;
     /// use aoc_helper::direction::cardinal_direction::CardinalDirection;
     /// 
-    /// let vertical_directions = [CardinalDirection::North, CardinalDirection::South];
+    /// let vertical_directions = vec![CardinalDirection::North, CardinalDirection::South];
     /// assert_eq!(vertical_directions, Direction::get_vertical());    
     /// ```
-     fn get_vertical() -> [Self; 2] where Self: Sized;
+     fn get_vertical() -> Vec<Self> where Self: Sized;
 
-    /// An array of all [`Direction`] variants.
+    /// A vector of all [`Direction`] variants. Types implementing this trait can have any number of variants (e.g. four for [`cardinal_direction::CardinalDirection`] or eight for [`octal_direction::OctalDirection`]).
     /// 
     /// # Example: 
     /// ```
@@ -60,7 +60,7 @@ pub trait Direction {
     /// let expected = vec![CardinalDirection::North, CardinalDirection::East, CardinalDirection::South, CardinalDirection::West];
     /// assert_eq!(expected, CardinalDirection::all().into_iter().collect::<Vec<_>>());
     /// ```
-    fn all() -> [Self; 4] where Self: Sized;
+    fn all() -> Vec<Self> where Self: Sized;
 
     /// Returns [`Direction`] that is opposite of this [`Direction`] ([`CardinalDirection::West`] <-> [`CardinalDirection::East`] and [`CardinalDirection::North`] <-> [`CardinalDirection::South`]). 
     /// 
@@ -150,8 +150,101 @@ pub trait Direction {
     /// assert_eq!(CardinalDirection::South, west.get_left());
     /// ```
     fn get_left(&self) -> Self where Self: Sized;
+
+    /// Returns the [`Turn`] that has to be made to face `other` after facing [`self`].
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `other` can not be reached with [`get_right`](Direction::get_right), [`get_left`](Direction::get_left) or [`get_opposite`](Direction::get_opposite) (e.g. a 45 degree turn between two [`octal_direction::OctalDirection`]s).
+    /// 
+    /// # Examples:
+    /// 
+    /// ```
+    /// use crate::aoc_helper::direction::{Direction, Turn};
+    /// use aoc_helper::direction::cardinal_direction::CardinalDirection;
+    /// 
+    /// let north = CardinalDirection::North;
+    /// assert_eq!(Turn::Right, north.turn_to(&CardinalDirection::East));
+    /// assert_eq!(Turn::Around, north.turn_to(&CardinalDirection::South));
+    /// ```
+    fn turn_to(&self, other: &Self) -> Turn where Self: Sized + PartialEq {
+        if self == other {
+            Turn::Straight
+        } else if &self.get_left() == other {
+            Turn::Left
+        } else if &self.get_right() == other {
+            Turn::Right
+        } else if &self.get_opposite() == other {
+            Turn::Around
+        } else {
+            panic!("The directions are not separated by a multiple of a right or left turn.")
+        }
+    }
+}
+
+/// The relationship between two [`Direction`]s, e.g. the turn a robot makes between two moves. See [`Direction::turn_to`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Turn {
+    Straight,
+    Left,
+    Right,
+    Around,
 }
 
 
 pub mod cardinal_direction;
+pub mod hex_direction;
+pub mod octal_direction;
 pub mod relative_direction;
+
+#[cfg(test)]
+mod test {
+    use std::fmt::Debug;
+
+    use super::{cardinal_direction::CardinalDirection, octal_direction::OctalDirection, relative_direction::RelativeDirection, *};
+
+    /// Check the properties that every [`Direction`] implementation should have, independent of the number of directions.
+    fn check_direction<D: Direction + PartialEq + Debug + Copy>(expected_count: usize) {
+        let all = D::all();
+        assert_eq!(expected_count, all.len());
+
+        for direction in all.iter() {
+            assert_eq!(*direction, D::from_offset(&direction.get_offset()));
+            assert_eq!(*direction, direction.get_opposite().get_opposite());
+            assert_eq!(*direction, direction.get_right().get_left());
+            assert_eq!(direction.get_opposite(), direction.get_right().get_right());
+        }
+
+        assert!(D::get_horizontal().iter().chain(D::get_vertical().iter()).all(|d| all.contains(d)));
+    }
+
+    #[test]
+    fn four_direction_enums_implement_direction() {
+        check_direction::<CardinalDirection>(4);
+        check_direction::<RelativeDirection>(4);
+    }
+
+    #[test]
+    fn turn_to_works() {
+        for direction in CardinalDirection::all() {
+            assert_eq!(Turn::Straight, direction.turn_to(&direction));
+            assert_eq!(Turn::Left, direction.turn_to(&direction.get_left()));
+            assert_eq!(Turn::Right, direction.turn_to(&direction.get_right()));
+            assert_eq!(Turn::Around, direction.turn_to(&direction.get_opposite()));
+        }
+
+        assert_eq!(Turn::Left, CardinalDirection::East.turn_to(&CardinalDirection::North));
+        assert_eq!(Turn::Right, CardinalDirection::West.turn_to(&CardinalDirection::North));
+    }
+
+    #[test]
+    #[should_panic]
+    fn turn_to_panics_on_diagonal_turn() {
+        let _ = OctalDirection::North.turn_to(&OctalDirection::NorthEast);
+    }
+
+    #[test]
+    fn eight_direction_enums_implement_direction() {
+        check_direction::<OctalDirection>(8);
+    }
+}
\ No newline at end of file
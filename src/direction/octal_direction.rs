@@ -0,0 +1,234 @@
+use super::{cardinal_direction::CardinalDirection, Direction};
+
+/// Eight directions: the four [`CardinalDirection`]s, and the four diagonals between them.
+/// These enums can be used in, for example, a 2D grid where diagonal cells are also neighbors (e.g. point (0,0) is to the NorthWest of point (1,1)).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum OctalDirection {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl OctalDirection {
+    /// Returns `true` if [`self`] is one of the four diagonal directions.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use aoc_helper::direction::octal_direction::OctalDirection;
+    ///
+    /// assert!(OctalDirection::NorthEast.is_diagonal());
+    /// assert!(!OctalDirection::North.is_diagonal());
+    /// ```
+    pub fn is_diagonal(&self) -> bool {
+        matches!(
+            self,
+            OctalDirection::NorthEast
+                | OctalDirection::SouthEast
+                | OctalDirection::SouthWest
+                | OctalDirection::NorthWest
+        )
+    }
+}
+
+impl From<CardinalDirection> for OctalDirection {
+    fn from(value: CardinalDirection) -> Self {
+        match value {
+            CardinalDirection::North => OctalDirection::North,
+            CardinalDirection::East => OctalDirection::East,
+            CardinalDirection::South => OctalDirection::South,
+            CardinalDirection::West => OctalDirection::West,
+        }
+    }
+}
+
+impl Direction for OctalDirection {
+    /// Return the horizontal directions (West and East):
+    /// ```
+    /// use crate::aoc_helper::direction::Direction;
+    /// use aoc_helper::direction::octal_direction::OctalDirection;
+    ///
+    /// assert_eq!(vec![OctalDirection::West, OctalDirection::East], OctalDirection::get_horizontal());
+    /// ```
+    fn get_horizontal() -> Vec<OctalDirection> {
+        vec![OctalDirection::West, OctalDirection::East]
+    }
+
+    /// Get a direction that will correspond to the given offset in a 2D grid. The offset should be in the format (row_offset, col_offset). The offset values should be one of -1, 0, or 1.
+    /// Note that (0, 0) is not a valid offset value, as that represents the current location.
+    ///
+    /// # Examples:
+    /// ```
+    /// use crate::aoc_helper::direction::Direction;
+    /// use aoc_helper::direction::octal_direction::OctalDirection;
+    ///
+    /// assert_eq!(OctalDirection::NorthEast, OctalDirection::from_offset(&(-1, 1)));
+    /// assert_eq!(OctalDirection::West, OctalDirection::from_offset(&(0, -1)));
+    /// ```
+    fn from_offset(offset: &(i8, i8)) -> OctalDirection {
+        match offset {
+            (-1, 0) => OctalDirection::North,
+            (-1, 1) => OctalDirection::NorthEast,
+            (0, 1) => OctalDirection::East,
+            (1, 1) => OctalDirection::SouthEast,
+            (1, 0) => OctalDirection::South,
+            (1, -1) => OctalDirection::SouthWest,
+            (0, -1) => OctalDirection::West,
+            (-1, -1) => OctalDirection::NorthWest,
+            (0, 0) => panic!("(0, 0) is not a valid offset, as it represents the current position."),
+            _ => panic!("Invalid format! The offset should be in the format (row_offset, col_offset), where both values must be either -1, 0, or 1.")
+        }
+    }
+
+    /// Return the vertical directions (North and South):
+    /// ```
+    /// use crate::aoc_helper::direction::Direction;
+    /// use aoc_helper::direction::octal_direction::OctalDirection;
+    ///
+    /// assert_eq!(vec![OctalDirection::North, OctalDirection::South], OctalDirection::get_vertical());
+    /// ```
+    fn get_vertical() -> Vec<OctalDirection> {
+        vec![OctalDirection::North, OctalDirection::South]
+    }
+
+    /// Iterate over all [`OctalDirection`] variants. The iterator starts at [`OctalDirection::North`], and moves clockwise.
+    fn all() -> Vec<OctalDirection> {
+        vec![
+            OctalDirection::North,
+            OctalDirection::NorthEast,
+            OctalDirection::East,
+            OctalDirection::SouthEast,
+            OctalDirection::South,
+            OctalDirection::SouthWest,
+            OctalDirection::West,
+            OctalDirection::NorthWest,
+        ]
+    }
+
+    /// Returns the [`OctalDirection`] that is opposite of this [`OctalDirection`] (e.g. [`OctalDirection::NorthEast`] <-> [`OctalDirection::SouthWest`]).
+    fn get_opposite(&self) -> OctalDirection {
+        self.get_right().get_right()
+    }
+
+    /// Get an offset that will correspond to this [`OctalDirection`] in a 2D grid.
+    ///
+    /// # Examples:
+    /// ```
+    /// use crate::aoc_helper::direction::Direction;
+    /// use aoc_helper::direction::octal_direction::OctalDirection;
+    ///
+    /// assert_eq!((-1, 1), OctalDirection::NorthEast.get_offset());
+    /// assert_eq!((1, 0), OctalDirection::South.get_offset());
+    /// ```
+    fn get_offset(&self) -> (i8, i8) {
+        match self {
+            OctalDirection::North => (-1, 0),
+            OctalDirection::NorthEast => (-1, 1),
+            OctalDirection::East => (0, 1),
+            OctalDirection::SouthEast => (1, 1),
+            OctalDirection::South => (1, 0),
+            OctalDirection::SouthWest => (1, -1),
+            OctalDirection::West => (0, -1),
+            OctalDirection::NorthWest => (-1, -1),
+        }
+    }
+
+    /// Returns the [`OctalDirection`] to the right of [`self`], i.e. turned 90 degrees clockwise.
+    ///
+    /// # Examples:
+    /// ```
+    /// use crate::aoc_helper::direction::Direction;
+    /// use aoc_helper::direction::octal_direction::OctalDirection;
+    ///
+    /// assert_eq!(OctalDirection::East, OctalDirection::North.get_right());
+    /// assert_eq!(OctalDirection::SouthEast, OctalDirection::NorthEast.get_right());
+    /// ```
+    fn get_right(&self) -> OctalDirection {
+        match self {
+            OctalDirection::North => OctalDirection::East,
+            OctalDirection::NorthEast => OctalDirection::SouthEast,
+            OctalDirection::East => OctalDirection::South,
+            OctalDirection::SouthEast => OctalDirection::SouthWest,
+            OctalDirection::South => OctalDirection::West,
+            OctalDirection::SouthWest => OctalDirection::NorthWest,
+            OctalDirection::West => OctalDirection::North,
+            OctalDirection::NorthWest => OctalDirection::NorthEast,
+        }
+    }
+
+    /// Returns the [`OctalDirection`] to the left of [`self`], i.e. turned 90 degrees counterclockwise.
+    ///
+    /// # Examples:
+    /// ```
+    /// use crate::aoc_helper::direction::Direction;
+    /// use aoc_helper::direction::octal_direction::OctalDirection;
+    ///
+    /// assert_eq!(OctalDirection::West, OctalDirection::North.get_left());
+    /// assert_eq!(OctalDirection::NorthWest, OctalDirection::NorthEast.get_left());
+    /// ```
+    fn get_left(&self) -> OctalDirection {
+        self.get_opposite().get_right()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn offsets_round_trip() {
+        for direction in OctalDirection::all() {
+            assert_eq!(
+                direction,
+                OctalDirection::from_offset(&direction.get_offset())
+            );
+        }
+    }
+
+    #[test]
+    fn get_opposite_works() {
+        assert_eq!(OctalDirection::South, OctalDirection::North.get_opposite());
+        assert_eq!(
+            OctalDirection::SouthWest,
+            OctalDirection::NorthEast.get_opposite()
+        );
+        assert_eq!(
+            OctalDirection::NorthWest,
+            OctalDirection::SouthEast.get_opposite()
+        );
+        assert_eq!(OctalDirection::East, OctalDirection::West.get_opposite());
+    }
+
+    #[test]
+    fn cardinal_directions_match() {
+        for direction in CardinalDirection::all() {
+            let octal = OctalDirection::from(direction);
+
+            assert!(!octal.is_diagonal());
+            assert_eq!(direction.get_offset(), octal.get_offset());
+            assert_eq!(
+                OctalDirection::from(direction.get_right()),
+                octal.get_right()
+            );
+        }
+
+        assert_eq!(
+            4,
+            OctalDirection::all()
+                .iter()
+                .filter(|d| d.is_diagonal())
+                .count()
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn from_offset_panics_on_invalid_offset() {
+        let _ = OctalDirection::from_offset(&(0, 2));
+    }
+}
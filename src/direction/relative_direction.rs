@@ -1,3 +1,5 @@
+use std::fmt::Display;
+
 use super::{cardinal_direction::CardinalDirection, Direction};
 
 
@@ -36,23 +38,48 @@ impl RelativeDirection {
             RelativeDirection::Left => CardinalDirection::West
         }
     }
+
+    /// Return the name of [`self`] as a word (e.g. "Up"). This is also what the [`Display`] implementation writes.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use aoc_helper::direction::relative_direction::RelativeDirection;
+    ///
+    /// assert_eq!("Up", RelativeDirection::Up.to_word());
+    /// assert_eq!("Up", RelativeDirection::Up.to_string());
+    /// ```
+    pub fn to_word(&self) -> &'static str {
+        match self {
+            RelativeDirection::Up => "Up",
+            RelativeDirection::Right => "Right",
+            RelativeDirection::Down => "Down",
+            RelativeDirection::Left => "Left",
+        }
+    }
+}
+
+impl Display for RelativeDirection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_word())
+    }
 }
 
 impl Direction for RelativeDirection {
-    fn get_horizontal() -> [Self; 2] where Self: Sized {
-        [RelativeDirection::Right, RelativeDirection::Left]
+    fn get_horizontal() -> Vec<Self> where Self: Sized {
+        vec![RelativeDirection::Right, RelativeDirection::Left]
     }
 
     fn from_offset(offset: &(i8, i8)) -> Self where Self:Sized {
         CardinalDirection::from_offset(offset).to_relative()
     }
 
-    fn get_vertical() -> [Self; 2] where Self: Sized {
-        [RelativeDirection::Up, RelativeDirection::Down]
+    fn get_vertical() -> Vec<Self> where Self: Sized {
+        vec![RelativeDirection::Up, RelativeDirection::Down]
     }
 
-    fn all() -> [Self; 4] where Self: Sized {
-        [RelativeDirection::Up, RelativeDirection::Right, RelativeDirection::Down, RelativeDirection::Left]
+    fn all() -> Vec<Self> where Self: Sized {
+        vec![RelativeDirection::Up, RelativeDirection::Right, RelativeDirection::Down, RelativeDirection::Left]
     }
 
     fn get_opposite(&self) -> Self where Self: Sized {
@@ -0,0 +1,123 @@
+use super::point::Point2D;
+
+/// Return the sum of the manhattan distances between every pair of `points`, after every empty row and column has been replaced by `factor` copies of itself (AoC 2023 Day 11).
+/// This is the scalable version of [`Grid::expand_empty`](crate::graph::grid::Grid::expand_empty): the expanded grid is never built, so large factors (e.g. one million) are cheap.
+/// The points use `x` as the column and `y` as the row.
+///
+/// # Arguments
+///
+/// * `points` - The points to measure the distances between (e.g. the galaxies).
+/// * `empty_rows` - The rows (`y` values) that contain no points.
+/// * `empty_cols` - The columns (`x` values) that contain no points.
+/// * `factor` - The number of rows (or columns) that each empty row (or column) is replaced with.
+///
+/// # Example
+///
+/// ```
+/// use aoc_helper::geometry::{expansion::expanded_distance, point::Point2D};
+///
+/// // The empty row between the two points is doubled
+/// let points = [Point2D::new(0, 0), Point2D::new(0, 2)];
+///
+/// assert_eq!(expanded_distance(&points, &[1], &[], 2), 3);
+/// ```
+pub fn expanded_distance(
+    points: &[Point2D<i64>],
+    empty_rows: &[i64],
+    empty_cols: &[i64],
+    factor: i64,
+) -> i64 {
+    let expand = |value: i64, empty: &[i64]| {
+        value + (factor - 1) * empty.iter().filter(|&&e| e < value).count() as i64
+    };
+
+    let xs = points.iter().map(|p| expand(p.x, empty_cols)).collect();
+    let ys = points.iter().map(|p| expand(p.y, empty_rows)).collect();
+
+    pairwise_distance_sum(xs) + pairwise_distance_sum(ys)
+}
+
+/// Sum |a - b| over every pair of `values`. After sorting, each value is the larger one in the pairs with all values before it.
+fn pairwise_distance_sum(mut values: Vec<i64>) -> i64 {
+    values.sort_unstable();
+
+    let mut prefix = 0;
+    let mut total = 0;
+
+    for (i, value) in values.into_iter().enumerate() {
+        total += value * i as i64 - prefix;
+        prefix += value;
+    }
+
+    total
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashSet;
+
+    use super::*;
+
+    fn galaxies() -> (Vec<Point2D<i64>>, Vec<i64>, Vec<i64>) {
+        let input = "...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#.....";
+
+        let points = input
+            .lines()
+            .enumerate()
+            .flat_map(|(y, line)| {
+                line.char_indices()
+                    .filter(|&(_, c)| c == '#')
+                    .map(move |(x, _)| Point2D::new(x as i64, y as i64))
+            })
+            .collect::<Vec<_>>();
+
+        let rows = points.iter().map(|p| p.y).collect::<HashSet<_>>();
+        let cols = points.iter().map(|p| p.x).collect::<HashSet<_>>();
+
+        let empty_rows = (0..10).filter(|y| !rows.contains(y)).collect();
+        let empty_cols = (0..10).filter(|x| !cols.contains(x)).collect();
+
+        (points, empty_rows, empty_cols)
+    }
+
+    #[test]
+    fn expanded_distance_solves_2023_day_11() {
+        let (points, empty_rows, empty_cols) = galaxies();
+
+        assert_eq!(expanded_distance(&points, &empty_rows, &empty_cols, 2), 374);
+        assert_eq!(
+            expanded_distance(&points, &empty_rows, &empty_cols, 10),
+            1030
+        );
+        assert_eq!(
+            expanded_distance(&points, &empty_rows, &empty_cols, 100),
+            8410
+        );
+    }
+
+    #[test]
+    fn expanded_distance_without_expansion() {
+        let (points, empty_rows, empty_cols) = galaxies();
+
+        let naive = points
+            .iter()
+            .enumerate()
+            .flat_map(|(i, a)| points[i + 1..].iter().map(|b| a.manhattan_distance_to(b)))
+            .sum::<i64>();
+
+        assert_eq!(
+            expanded_distance(&points, &empty_rows, &empty_cols, 1),
+            naive
+        );
+        assert_eq!(expanded_distance(&[], &[], &[], 10), 0);
+    }
+}
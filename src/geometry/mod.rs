@@ -1,2 +1,5 @@
+pub mod expansion;
 pub mod point;
+pub mod point_index;
 pub mod polygon;
+pub mod sparse_grid;
\ No newline at end of file
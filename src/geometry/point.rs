@@ -84,11 +84,237 @@ where
     {
         (self.x - other.x).abs() + (self.y - other.y).abs()
     }
+
+    /// Returns the points that are exactly 'radius' away from [`self`], measured by manhattan distance (i.e. the outline of a diamond, not the filled diamond).
+    /// This is useful when only the points just outside of a range have to be checked (e.g. AoC 2022 Day 15).
+    /// The points start at (x + radius, y), and follow the outline towards (x, y + radius). A radius of 0 returns [`self`], and a negative radius returns no points.
+    ///
+    /// # Arguments:
+    ///
+    /// * 'radius' - The manhattan distance of every returned point.
+    ///
+    /// # Example:
+    /// ```
+    /// use aoc_helper::geometry::point::Point2D;
+    ///
+    /// let ring = Point2D::new(0, 0).manhattan_ring(1);
+    ///
+    /// assert_eq!(vec![Point2D::new(1, 0), Point2D::new(0, 1), Point2D::new(-1, 0), Point2D::new(0, -1)], ring);
+    /// ```
+    pub fn manhattan_ring(&self, radius: T) -> Vec<Point2D<T>>
+    where
+        T: PrimInt + Signed
+    {
+        if radius.is_zero() {
+            return vec![*self];
+        }
+
+        let mut points = Vec::new();
+
+        for edge in 0..4 {
+            let mut i = T::zero();
+            while i < radius {
+                let (dx, dy) = match edge {
+                    0 => (radius - i, i),
+                    1 => (-i, radius - i),
+                    2 => (i - radius, -i),
+                    _ => (i, i - radius),
+                };
+                points.push(Point2D::new(self.x + dx, self.y + dy));
+                i = i + T::one();
+            }
+        }
+
+        points
+    }
+
+    /// Returns the point that is 'offset' away from [`self`], or [`None`] if a coordinate would overflow or underflow T (e.g. when stepping left from x = 0 with an unsigned type).
+    /// The offset is in the (row_offset, col_offset) format used by [`Direction::get_offset`](crate::direction::Direction::get_offset), so the first value is added to y, and the second value to x.
+    ///
+    /// # Arguments:
+    ///
+    /// * 'offset' - The (row_offset, col_offset) to add.
+    ///
+    /// # Example:
+    /// ```
+    /// use aoc_helper::geometry::point::Point2D;
+    ///
+    /// let origin: Point2D<usize> = Point2D::new(0, 0);
+    ///
+    /// assert_eq!(Some(Point2D::new(1, 0)), origin.checked_add_offset((0, 1)));
+    /// assert_eq!(None, origin.checked_add_offset((-1, 0)));
+    /// ```
+    pub fn checked_add_offset(&self, offset: (i8, i8)) -> Option<Point2D<T>>
+    where
+        T: PrimInt
+    {
+        Some(Point2D {
+            x: checked_add_i8(self.x, offset.1)?,
+            y: checked_add_i8(self.y, offset.0)?,
+        })
+    }
 }
 
+/// Add 'offset' to 'value', returning [`None`] if the result does not fit into T.
+fn checked_add_i8<T: PrimInt>(value: T, offset: i8) -> Option<T> {
+    let magnitude = T::from(offset.unsigned_abs())?;
+
+    if offset < 0 {
+        value.checked_sub(&magnitude)
+    } else {
+        value.checked_add(&magnitude)
+    }
+}
+
+/// Represents a point in three dimensional space. The type of T indicates the type of the x, y and z coordinates.
+///
+/// # Example:
+///
+/// ```
+/// use aoc_helper::geometry::point::Point3D;
+///
+/// let p = Point3D { x: 1, y: -2, z: 3 };
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point3D<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T> Point3D<T>
+where
+    T: Num
+{
+    /// Creates a new [`Point3D<T>`].
+    pub fn new(x: T, y: T, z: T) -> Point3D<T> {
+        Point3D { x, y, z }
+    }
+
+    /// Returns the distance to the other [`Point3D`] measured along axes at right angles.
+    /// T must be a signed type.
+    ///
+    /// # Arguments:
+    ///
+    /// * 'other' - A reference to another [`Point3D`] struct.
+    ///
+    /// # Example:
+    /// ```
+    /// use aoc_helper::geometry::point::Point3D;
+    ///
+    /// let p1 = Point3D::new(1, 2, 3);
+    /// let p2 = Point3D::new(-1, 2, 5);
+    ///
+    /// assert_eq!(4, p1.manhattan_distance_to(&p2));
+    /// ```
+    pub fn manhattan_distance_to(&self, other: &Point3D<T>) -> T
+    where
+        T: PrimInt + Signed
+    {
+        (self.x - other.x).abs() + (self.y - other.y).abs() + (self.z - other.z).abs()
+    }
+
+    /// Returns every point with integer coordinates whose manhattan distance from [`self`] is at most 'radius', including [`self`].
+    /// The points are ordered layer by layer: by z first, then by y, then by x.
+    /// If 'radius' is negative, the result is empty.
+    ///
+    /// # Arguments:
+    ///
+    /// * 'radius' - The largest manhattan distance a returned point can have.
+    ///
+    /// # Example:
+    /// ```
+    /// use aoc_helper::geometry::point::Point3D;
+    ///
+    /// let center = Point3D::new(0, 0, 0);
+    /// let points = center.points_within_manhattan(1);
+    ///
+    /// assert_eq!(7, points.len());
+    /// assert_eq!(Point3D::new(0, 0, -1), points[0]);
+    /// ```
+    pub fn points_within_manhattan(&self, radius: T) -> Vec<Point3D<T>>
+    where
+        T: PrimInt + Signed
+    {
+        let mut points = Vec::new();
+
+        let mut dz = -radius;
+        while dz <= radius {
+            let remaining_z = radius - dz.abs();
+
+            let mut dy = -remaining_z;
+            while dy <= remaining_z {
+                let remaining_y = remaining_z - dy.abs();
+
+                let mut dx = -remaining_y;
+                while dx <= remaining_y {
+                    points.push(Point3D::new(self.x + dx, self.y + dy, self.z + dz));
+                    dx = dx + T::one();
+                }
+
+                dy = dy + T::one();
+            }
+
+            dz = dz + T::one();
+        }
+
+        points
+    }
+
+    /// Returns [`self`] under each of the 24 rotations that map the coordinate axes onto themselves (i.e. every orientation of a cube), without reflections.
+    /// The rotation at a given index is the same for every point, so the results for different points can be matched up by index. Index 0 is the identity.
+    ///
+    /// # Example:
+    /// ```
+    /// use std::collections::HashSet;
+    /// use aoc_helper::geometry::point::Point3D;
+    ///
+    /// let p = Point3D::new(1, 2, 3);
+    /// let rotations = p.all_rotations();
+    ///
+    /// assert_eq!(p, rotations[0]);
+    /// assert_eq!(24, rotations.iter().collect::<HashSet<_>>().len());
+    /// ```
+    pub fn all_rotations(&self) -> [Point3D<T>; 24]
+    where
+        T: PrimInt + Signed
+    {
+        // Each rotation is a permutation of the axes, combined with a sign for each axis.
+        // Only combinations with a determinant of +1 are rotations, the rest are reflections:
+        // even permutations need an even number of negated axes, odd permutations an odd number.
+        const PERMUTATIONS: [([usize; 3], bool); 6] = [
+            ([0, 1, 2], true),
+            ([1, 2, 0], true),
+            ([2, 0, 1], true),
+            ([0, 2, 1], false),
+            ([2, 1, 0], false),
+            ([1, 0, 2], false),
+        ];
+
+        let coordinates = [self.x, self.y, self.z];
+
+        std::array::from_fn(|i| {
+            let (permutation, is_even) = PERMUTATIONS[i / 4];
+
+            let x_negated = i & 1 == 1;
+            let y_negated = i & 2 == 2;
+            let z_negated = (x_negated == y_negated) != is_even;
+
+            let signed = |value: T, negated: bool| if negated { -value } else { value };
+
+            Point3D::new(
+                signed(coordinates[permutation[0]], x_negated),
+                signed(coordinates[permutation[1]], y_negated),
+                signed(coordinates[permutation[2]], z_negated),
+            )
+        })
+    }
+}
 
 #[cfg(test)]
 mod test {
+    use std::collections::HashSet;
+
     use super::*;
 
     #[test]
@@ -102,6 +328,21 @@ mod test {
         assert_eq!(expected, actual);
     } 
 
+    #[test]
+    fn manhattan_ring_works() {
+        let center: Point2D<i64> = Point2D::new(3, -2);
+
+        let ring = center.manhattan_ring(2);
+
+        assert_eq!(8, ring.len());
+        assert_eq!(8, ring.iter().collect::<HashSet<_>>().len());
+        assert!(ring.iter().all(|p| p.manhattan_distance_to(&center) == 2));
+
+        assert_eq!(vec![center], center.manhattan_ring(0));
+        assert!(center.manhattan_ring(-1).is_empty());
+        assert_eq!(400, center.manhattan_ring(100).len());
+    }
+
     #[test]
     fn manhattan_distance_to_works() {
         let p1: Point2D<isize> = Point2D {x: 1, y: 4};
@@ -121,6 +362,22 @@ mod test {
         assert_eq!(expected_2, actual_2);
     }
 
+    #[test]
+    fn checked_add_offset_works() {
+        let origin: Point2D<usize> = Point2D::new(0, 0);
+
+        assert_eq!(None, origin.checked_add_offset((-1, 0)));
+        assert_eq!(None, origin.checked_add_offset((0, -1)));
+        assert_eq!(Some(Point2D::new(1, 1)), origin.checked_add_offset((1, 1)));
+
+        let p: Point2D<i32> = Point2D::new(0, 0);
+        assert_eq!(Some(Point2D::new(-1, 2)), p.checked_add_offset((2, -1)));
+
+        let max: Point2D<u8> = Point2D::new(u8::MAX, 0);
+        assert_eq!(None, max.checked_add_offset((0, 1)));
+        assert_eq!(Some(Point2D::new(u8::MAX - 1, 1)), max.checked_add_offset((1, -1)));
+    }
+
     #[test]
     fn equal_works() {
         let p1 = Point2D {x: 42, y: 42};
@@ -128,4 +385,69 @@ mod test {
 
         assert_eq!(p1, p2);
     }
+
+    #[test]
+    fn point_3d_manhattan_distance_to_works() {
+        let p1: Point3D<i64> = Point3D::new(1105, -1205, 1229);
+        let p2: Point3D<i64> = Point3D::new(-92, -2380, -20);
+
+        assert_eq!(3621, p1.manhattan_distance_to(&p2));
+    }
+
+    #[test]
+    fn points_within_manhattan_works() {
+        let center: Point3D<i32> = Point3D::new(5, -3, 2);
+
+        let unit = center.points_within_manhattan(1);
+        assert_eq!(7, unit.len());
+        assert!(unit.contains(&center));
+        assert!(unit.iter().all(|p| p.manhattan_distance_to(&center) <= 1));
+
+        let expected_order = vec![
+            Point3D::new(5, -3, 1),
+            Point3D::new(5, -4, 2),
+            Point3D::new(4, -3, 2),
+            center,
+            Point3D::new(6, -3, 2),
+            Point3D::new(5, -2, 2),
+            Point3D::new(5, -3, 3),
+        ];
+        assert_eq!(expected_order, unit);
+
+        // The octahedral numbers: 1, 7, 25, 63, ...
+        assert_eq!(1, center.points_within_manhattan(0).len());
+        assert_eq!(25, center.points_within_manhattan(2).len());
+        assert_eq!(63, center.points_within_manhattan(3).len());
+        assert!(center.points_within_manhattan(-1).is_empty());
+    }
+
+    #[test]
+    fn all_rotations_returns_24_distinct_orientations() {
+        let p: Point3D<i32> = Point3D::new(1, 2, 3);
+        let rotations = p.all_rotations();
+
+        assert_eq!(p, rotations[0]);
+        assert_eq!(24, rotations.iter().collect::<HashSet<_>>().len());
+
+        // Rotations preserve the distance from the origin
+        let origin = Point3D::new(0, 0, 0);
+        assert!(rotations.iter().all(|r| r.manhattan_distance_to(&origin) == 6));
+
+        // The rotations form a group: rotating any orientation again yields the same 24 orientations
+        let all = rotations.iter().collect::<HashSet<_>>();
+        for rotated in rotations {
+            assert_eq!(all, rotated.all_rotations().iter().collect::<HashSet<_>>());
+        }
+    }
+
+    #[test]
+    fn all_rotations_excludes_reflections() {
+        // A reflection of the x axis is not a rotation
+        let p: Point3D<i32> = Point3D::new(1, 2, 3);
+
+        assert!(!p.all_rotations().contains(&Point3D::new(-1, 2, 3)));
+        assert!(!p.all_rotations().contains(&Point3D::new(2, 1, 3)));
+        assert!(p.all_rotations().contains(&Point3D::new(-1, -2, 3)));
+        assert!(p.all_rotations().contains(&Point3D::new(2, -1, 3)));
+    }
 }
\ No newline at end of file
@@ -0,0 +1,114 @@
+use num_traits::{PrimInt, Signed};
+
+use super::point::Point2D;
+
+/// A set of [`Point2D`]s that can answer nearest neighbor queries by manhattan distance (e.g. "which sensor is closest to this beacon").
+/// The points are currently searched with a linear scan. The storage is private, so a faster structure (e.g. a k-d tree) can replace it without changing the API.
+///
+/// # Example:
+///
+/// ```
+/// use aoc_helper::geometry::{point::Point2D, point_index::PointIndex};
+///
+/// let index: PointIndex<i32> = [Point2D::new(0, 0), Point2D::new(10, 10)].into_iter().collect();
+///
+/// assert_eq!(Some(Point2D::new(10, 10)), index.nearest(Point2D::new(7, 6)));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct PointIndex<T> {
+    points: Vec<Point2D<T>>,
+}
+
+impl<T> PointIndex<T>
+where
+    T: PrimInt + Signed,
+{
+    /// Creates a new [`PointIndex<T>`] containing 'points'.
+    pub fn new(points: Vec<Point2D<T>>) -> PointIndex<T> {
+        PointIndex { points }
+    }
+
+    /// Add 'point' to the index.
+    pub fn insert(&mut self, point: Point2D<T>) {
+        self.points.push(point);
+    }
+
+    /// Returns the number of points in the index.
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    /// Returns `true` if the index contains no points.
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    /// Returns the point closest to 'query' by manhattan distance, or [`None`] if the index is empty.
+    /// If multiple points are equally close, the one that was added first is returned.
+    ///
+    /// # Arguments:
+    ///
+    /// * 'query' - The point to measure the distances from. It does not have to be in the index.
+    pub fn nearest(&self, query: Point2D<T>) -> Option<Point2D<T>> {
+        self.points
+            .iter()
+            .min_by_key(|p| p.manhattan_distance_to(&query))
+            .copied()
+    }
+}
+
+impl<T> FromIterator<Point2D<T>> for PointIndex<T>
+where
+    T: PrimInt + Signed,
+{
+    fn from_iter<I: IntoIterator<Item = Point2D<T>>>(iter: I) -> Self {
+        PointIndex::new(iter.into_iter().collect())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn nearest_returns_closest_point() {
+        let points = vec![
+            Point2D::new(2, 18),
+            Point2D::new(-2, 15),
+            Point2D::new(10, 16),
+            Point2D::new(15, 3),
+            Point2D::new(21, 22),
+        ];
+        let index = PointIndex::new(points);
+
+        assert_eq!(
+            Some(Point2D::new(-2, 15)),
+            index.nearest(Point2D::new(0, 14))
+        );
+        assert_eq!(
+            Some(Point2D::new(10, 16)),
+            index.nearest(Point2D::new(9, 16))
+        );
+        assert_eq!(
+            Some(Point2D::new(15, 3)),
+            index.nearest(Point2D::new(20, 1))
+        );
+        assert_eq!(
+            Some(Point2D::new(21, 22)),
+            index.nearest(Point2D::new(21, 22))
+        );
+    }
+
+    #[test]
+    fn nearest_prefers_first_point_on_tie() {
+        let mut index = PointIndex::default();
+        assert!(index.is_empty());
+        assert_eq!(None, index.nearest(Point2D::new(0, 0)));
+
+        index.insert(Point2D::new(1, 0));
+        index.insert(Point2D::new(0, -1));
+
+        assert_eq!(2, index.len());
+        assert_eq!(Some(Point2D::new(1, 0)), index.nearest(Point2D::new(0, 0)));
+    }
+}
@@ -3,8 +3,6 @@ use std::cmp::{max, min};
 
 use num_traits::Num;
 
-use crate::{direction::relative_direction::RelativeDirection, iter_ext::IterExt};
-
 use super::point::Point2D;
 
 /// A polygon is a shape defined by three or more vertices (points).
@@ -18,12 +16,7 @@ pub struct Polygon<T> {
     vertices: Vec<Point2D<T>>,
 }
 
-impl<T> Polygon<T>
-where
-    T: Num + Ord,
-    T: Clone + Copy,
-    f64: From<T>,
-{
+impl<T> Polygon<T> {
     /// Creates a new [`Polygon<T>`], with no vertices.
     pub fn new() -> Polygon<T> {
         Polygon {
@@ -91,7 +84,14 @@ where
     pub fn add_vertex_front(&mut self, vertex: Point2D<T>) {
         self.vertices.insert(0, vertex);
     }
+}
 
+impl<T> Polygon<T>
+where
+    T: Num + Ord,
+    T: Clone + Copy,
+    f64: From<T>,
+{
     /// Returns the perimeter of this [`Polygon<T>`].
     /// The perimeter is simply the sum of the lengths of all the lines that make up this polygon.
     /// For example, given a polygon with three vertices [a, b, c], we can calculate the perimeter such as this:
@@ -240,14 +240,123 @@ where
 
         result
     }
+
+    /// Check if the given [point] is inside this [`Polygon<T>`], using the winding number algorithm.
+    /// Unlike [`Polygon::contains_point`], points that lie on the boundary of the polygon (including its vertices) are always considered to be inside.
+    /// This gives consistent results for points on horizontal edges, at vertices, and for polygons that touch themselves.
+    ///
+    /// # Arguments
+    ///
+    /// * `point` - The point that is being tested
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc_helper::geometry::point::Point2D;
+    /// use aoc_helper::geometry::polygon::Polygon;
+    ///
+    /// let vertices = vec![Point2D::new(0, 0), Point2D::new(4, 0), Point2D::new(4, 4), Point2D::new(0, 4)];
+    /// let polygon = Polygon::new_with_vertices(vertices);
+    ///
+    /// assert!(polygon.contains_point_winding(Point2D::new(2, 2)));
+    /// assert!(polygon.contains_point_winding(Point2D::new(2, 4))); // On the top edge
+    /// assert!(!polygon.contains_point_winding(Point2D::new(5, 2)));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if this polygon does not have at least three vertices.
+    pub fn contains_point_winding(&self, point: Point2D<T>) -> bool {
+        let len = self.vertices.len();
+
+        if len < 3 {
+            panic!("Must have at least three vertices in a polygon.");
+        }
+
+        // From: https://web.archive.org/web/20130126163405/http://geomalgorithms.com/a03-_inclusion.html
+        let mut winding_number = 0;
+
+        for i in 0..len {
+            let start = self.vertices[i];
+            let end = self.vertices[(i + 1) % len];
+
+            let cross = cross_product(start, end, point);
+
+            if cross == T::zero()
+                && point.x >= min(start.x, end.x)
+                && point.x <= max(start.x, end.x)
+                && point.y >= min(start.y, end.y)
+                && point.y <= max(start.y, end.y)
+            {
+                return true; // The point is on the boundary
+            }
+
+            if start.y <= point.y {
+                if end.y > point.y && cross > T::zero() {
+                    winding_number += 1; // Upward crossing, point is left of the edge
+                }
+            } else if end.y <= point.y && cross < T::zero() {
+                winding_number -= 1; // Downward crossing, point is right of the edge
+            }
+        }
+
+        winding_number != 0
+    }
 }
 
-impl<T> Default for Polygon<T>
+/// Returns the z component of the cross product of (end - start) and (point - start).
+/// The result is positive if `point` is to the left of the line going through `start` and `end`, negative if it is to the right, and zero if the three points are collinear.
+fn cross_product<T>(start: Point2D<T>, end: Point2D<T>, point: Point2D<T>) -> T
 where
-    T: Num + Ord,
-    T: Clone + Copy,
-    f64: From<T>,
+    T: Num + Copy,
 {
+    (end.x - start.x) * (point.y - start.y) - (point.x - start.x) * (end.y - start.y)
+}
+
+impl<T> Polygon<T>
+where
+    T: Copy + Into<i128>,
+{
+    /// Returns twice the area of this [`Polygon<T>`], calculated using the Shoelace formula with integer arithmetic only.
+    /// Unlike [`Polygon::area`], this does not require `f64: From<T>`, so it works with `i64` coordinates, and the result is exact even when the coordinates are too large to be represented exactly as an [`f64`].
+    /// The area of a polygon with integer vertices is always a multiple of 0.5, so doubling it keeps the result an integer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc_helper::geometry::point::Point2D;
+    /// use aoc_helper::geometry::polygon::Polygon;
+    ///
+    /// let vertices = vec![Point2D::new(0_i64, 0), Point2D::new(1, 1), Point2D::new(0, 2)];
+    /// let polygon = Polygon::new_with_vertices(vertices);
+    ///
+    /// assert_eq!(2, polygon.area_doubled_i64());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if this polygon does not have at least three vertices.
+    pub fn area_doubled_i64(&self) -> i128 {
+        let len = self.vertices.len();
+
+        if len < 3 {
+            panic!("Must have at least three vertices in a polygon.");
+        }
+
+        let area: i128 = (0..len)
+            .map(|i| {
+                let current = self.vertices[i];
+                let next = self.vertices[(i + 1) % len];
+
+                current.x.into() * next.y.into() - next.x.into() * current.y.into()
+            })
+            .sum();
+
+        area.abs()
+    }
+}
+
+impl<T> Default for Polygon<T> {
     fn default() -> Self {
         Self::new()
     }
@@ -255,6 +364,8 @@ where
 
 #[cfg(test)]
 pub mod test {
+    use crate::{direction::relative_direction::RelativeDirection, iter_ext::IterExt, math::round_to};
+
     use super::*;
 
     #[test]
@@ -288,7 +399,7 @@ pub mod test {
 
         let perimeter = polygon.perimeter();
         // Round to 3 decimal places
-        let perimeter_rounded = round(perimeter, 3);
+        let perimeter_rounded = round_to(perimeter, 3);
 
         assert_eq!(4.828, perimeter_rounded);
 
@@ -306,7 +417,7 @@ pub mod test {
         let polygon = Polygon::new_with_vertices(vertices);
 
         let perimeter = polygon.perimeter();
-        let perimeter_rounded = round(perimeter, 3);
+        let perimeter_rounded = round_to(perimeter, 3);
 
         assert_eq!(611.803, perimeter_rounded);
     }
@@ -318,7 +429,7 @@ pub mod test {
 
         let area = polygon.area();
         // Round to 3 decimal places
-        let area_rounded = round(area, 3);
+        let area_rounded = round_to(area, 3);
 
         assert_eq!(1_f64, area_rounded);
 
@@ -334,11 +445,34 @@ pub mod test {
 
         let area = polygon.area();
         // Round to 3 decimal places
-        let area_rounded = round(area, 3);
+        let area_rounded = round_to(area, 3);
 
         assert_eq!(3975_f64, area_rounded);
     }
 
+    #[test]
+    fn can_calculate_area_doubled_with_large_i64_coordinates() {
+        // 2^53 + 1 cannot be represented exactly as an f64
+        let big = (1_i64 << 53) + 1;
+        let vertices = vec![
+            Point2D::new(0_i64, 0),
+            Point2D::new(big, 0),
+            Point2D::new(big, big),
+            Point2D::new(0, big),
+        ];
+        let polygon = Polygon::new_with_vertices(vertices);
+
+        let expected = 2 * (big as i128) * (big as i128);
+        assert_eq!(expected, polygon.area_doubled_i64());
+        assert_ne!(expected, 2 * ((big as f64) * (big as f64)) as i128);
+
+        // The winding order does not matter
+        let vertices = vec![Point2D::new(0_i64, 0), Point2D::new(0, 2), Point2D::new(1, 1)];
+        let polygon = Polygon::new_with_vertices(vertices);
+
+        assert_eq!(2, polygon.area_doubled_i64());
+    }
+
     #[test]
     fn point_in_polygon_works() {
         let vertices = vec![Point2D::new(0, 0), Point2D::new(3, 0), Point2D::new(0, 4)];
@@ -355,6 +489,70 @@ pub mod test {
         assert!(polygon.contains_point(Point2D::new(2, 1)));
     }
 
+    #[test]
+    fn point_in_polygon_winding_works() {
+        let vertices = vec![Point2D::new(0, 0), Point2D::new(3, 0), Point2D::new(0, 4)];
+        let polygon = Polygon::new_with_vertices(vertices);
+
+        assert!(!polygon.contains_point_winding(Point2D::new(-1, 0)));
+        assert!(!polygon.contains_point_winding(Point2D::new(2, 2)));
+        assert!(!polygon.contains_point_winding(Point2D::new(4, 0)));
+
+        assert!(polygon.contains_point_winding(Point2D::new(1, 1)));
+        assert!(polygon.contains_point_winding(Point2D::new(1, 2)));
+        assert!(polygon.contains_point_winding(Point2D::new(2, 1)));
+    }
+
+    #[test]
+    fn point_on_horizontal_edge_ray_casting_and_winding_differ() {
+        let vertices = vec![
+            Point2D::new(0, 0),
+            Point2D::new(4, 0),
+            Point2D::new(4, 4),
+            Point2D::new(0, 4),
+        ];
+        let polygon = Polygon::new_with_vertices(vertices);
+
+        // Both methods agree on points that are clearly inside or outside
+        assert!(polygon.contains_point(Point2D::new(2, 2)));
+        assert!(polygon.contains_point_winding(Point2D::new(2, 2)));
+        assert!(!polygon.contains_point(Point2D::new(6, 2)));
+        assert!(!polygon.contains_point_winding(Point2D::new(6, 2)));
+
+        // Ray casting treats the bottom and top horizontal edges differently
+        assert!(polygon.contains_point(Point2D::new(2, 0)));
+        assert!(!polygon.contains_point(Point2D::new(2, 4)));
+        assert!(!polygon.contains_point(Point2D::new(4, 4)));
+
+        // The winding number method considers the whole boundary to be inside
+        assert!(polygon.contains_point_winding(Point2D::new(2, 0)));
+        assert!(polygon.contains_point_winding(Point2D::new(2, 4)));
+        assert!(polygon.contains_point_winding(Point2D::new(4, 4)));
+        assert!(polygon.contains_point_winding(Point2D::new(0, 2)));
+    }
+
+    #[test]
+    fn point_in_self_touching_polygon_winding_works() {
+        // Two squares that touch at (2, 2)
+        let vertices = vec![
+            Point2D::new(0, 0),
+            Point2D::new(2, 0),
+            Point2D::new(2, 2),
+            Point2D::new(4, 2),
+            Point2D::new(4, 4),
+            Point2D::new(2, 4),
+            Point2D::new(2, 2),
+            Point2D::new(0, 2),
+        ];
+        let polygon = Polygon::new_with_vertices(vertices);
+
+        assert!(polygon.contains_point_winding(Point2D::new(1, 1)));
+        assert!(polygon.contains_point_winding(Point2D::new(3, 3)));
+        assert!(polygon.contains_point_winding(Point2D::new(2, 2)));
+        assert!(!polygon.contains_point_winding(Point2D::new(1, 3)));
+        assert!(!polygon.contains_point_winding(Point2D::new(3, 1)));
+    }
+
     #[test]
     fn can_calculate_day_18() {
         // Example from AoC 2023 Day 18, Part 1
@@ -408,32 +606,25 @@ U 2 (#7a21e3)";
         vertices
     }
 
-    /// Round a floating point number to a given amount of decimal places
-    fn round(number: f64, decimals: u32) -> f64 {
-        let n = 10_u32.pow(decimals);
-
-        (number * (n as f64)).round() / (n as f64)
+    struct Dig {
+        amount: i32,
+        direction: RelativeDirection,
     }
-}
 
-struct Dig {
-    amount: i32,
-    direction: RelativeDirection,
-}
-
-impl Dig {
-    fn new(line: &str) -> Dig {
-        let split = line.split(' ').collect_vec();
-        let direction = match split[0] {
-            "U" => RelativeDirection::Up,
-            "D" => RelativeDirection::Down,
-            "R" => RelativeDirection::Right,
-            "L" => RelativeDirection::Left,
-            _ => panic!(),
-        };
+    impl Dig {
+        fn new(line: &str) -> Dig {
+            let split = line.split(' ').collect_vec();
+            let direction = match split[0] {
+                "U" => RelativeDirection::Up,
+                "D" => RelativeDirection::Down,
+                "R" => RelativeDirection::Right,
+                "L" => RelativeDirection::Left,
+                _ => panic!(),
+            };
 
-        let amount = split[1].parse::<i32>().unwrap();
+            let amount = split[1].parse::<i32>().unwrap();
 
-        Dig { amount, direction }
+            Dig { amount, direction }
+        }
     }
 }
@@ -0,0 +1,456 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::graph::grid::Grid;
+
+use super::point::Point2D;
+
+/// An unbounded 2D grid, that only stores the cells that are present (e.g. the active cells of a cellular automaton).
+/// Unlike [`Grid`](crate::graph::grid::Grid), the coordinates can be negative, and the grid can grow in any direction, which makes it a good fit for simulations that spread without bounds (e.g. AoC 2017 Day 22).
+/// Points use `x` for the column and `y` for the row.
+///
+/// # Example:
+///
+/// ```
+/// use aoc_helper::geometry::{point::Point2D, sparse_grid::SparseGrid};
+///
+/// let mut grid = SparseGrid::new();
+///
+/// grid.insert(Point2D::new(-5, 3), '#');
+///
+/// assert_eq!(grid.get(&Point2D::new(-5, 3)), Some(&'#'));
+/// assert_eq!(grid.get(&Point2D::new(0, 0)), None);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Point2D<i64>, T>,
+}
+
+impl<T> SparseGrid<T> {
+    /// Creates a new, empty [`SparseGrid<T>`].
+    pub fn new() -> SparseGrid<T> {
+        SparseGrid {
+            cells: HashMap::new(),
+        }
+    }
+
+    /// Store `data` at `point`. Returns the data that was previously stored there, if any.
+    pub fn insert(&mut self, point: Point2D<i64>, data: T) -> Option<T> {
+        self.cells.insert(point, data)
+    }
+
+    /// Return a reference to the data stored at `point`, or [`None`] if the cell is not present.
+    pub fn get(&self, point: &Point2D<i64>) -> Option<&T> {
+        self.cells.get(point)
+    }
+
+    /// Remove the cell at `point`, and return its data.
+    pub fn remove(&mut self, point: &Point2D<i64>) -> Option<T> {
+        self.cells.remove(point)
+    }
+
+    /// Return the number of cells that are present.
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    /// Return `true` if no cells are present.
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Return an iterator over the present cells and their data, in arbitrary order.
+    pub fn iter(&self) -> impl Iterator<Item = (&Point2D<i64>, &T)> {
+        self.cells.iter()
+    }
+
+    /// Calculate the next generation of a cellular automaton, and return it as a new grid.
+    /// Only the present cells and the cells next to them (including diagonally) can be present in the next generation, so the work done is proportional to the number of present cells, not to the area they cover.
+    /// Every cell is updated at the same time: `rule` always sees the current generation.
+    ///
+    /// # Arguments
+    ///
+    /// * `rule` - A function that receives the data of a cell ([`None`] if it is not present) and the data of its present neighbors, and returns the next state of the cell ([`None`] to leave it out).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use aoc_helper::geometry::{point::Point2D, sparse_grid::SparseGrid};
+    ///
+    /// // Conway's Game of Life
+    /// let life = |cell: Option<&()>, neighbors: &[&()]| match (cell, neighbors.len()) {
+    ///     (Some(_), 2 | 3) | (None, 3) => Some(()),
+    ///     _ => None,
+    /// };
+    ///
+    /// // A blinker
+    /// let grid: SparseGrid<()> = (-1..=1).map(|x| (Point2D::new(x, 0), ())).collect();
+    /// let next = grid.step_automaton(life);
+    ///
+    /// assert!((-1..=1).all(|y| next.get(&Point2D::new(0, y)).is_some()));
+    /// assert_eq!(next.step_automaton(life), grid);
+    /// ```
+    pub fn step_automaton<F>(&self, rule: F) -> SparseGrid<T>
+    where
+        F: Fn(Option<&T>, &[&T]) -> Option<T>,
+    {
+        let candidates: HashSet<_> = self
+            .cells
+            .keys()
+            .flat_map(|&point| surrounding(point).chain(std::iter::once(point)))
+            .collect();
+
+        let cells = candidates
+            .into_iter()
+            .filter_map(|point| {
+                let neighbors = surrounding(point)
+                    .filter_map(|neighbor| self.cells.get(&neighbor))
+                    .collect::<Vec<_>>();
+
+                rule(self.cells.get(&point), &neighbors).map(|data| (point, data))
+            })
+            .collect();
+
+        SparseGrid { cells }
+    }
+}
+
+impl<T: Clone> SparseGrid<T> {
+    /// Convert this grid into a dense [`Grid`], covering the smallest rectangle that contains every present cell (e.g. to print the final state of a simulation).
+    /// Cells that are not present are filled with `default`. The top left cell of the dense grid is the point with the smallest `x` and `y`, so the point (`x`, `y`) ends up in row `y - min_y` and column `x - min_x`.
+    /// An empty [`SparseGrid`] is converted into an empty [`Grid`].
+    ///
+    /// # Arguments
+    ///
+    /// * `default` - The data of the cells that are not present.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use aoc_helper::geometry::{point::Point2D, sparse_grid::SparseGrid};
+    ///
+    /// let sparse: SparseGrid<char> = [(Point2D::new(-1, 0), '#'), (Point2D::new(1, 1), '#')].into_iter().collect();
+    ///
+    /// assert_eq!(sparse.to_grid('.').to_vec(), vec![vec!['#', '.', '.'], vec!['.', '.', '#']]);
+    /// ```
+    pub fn to_grid(&self, default: T) -> Grid<T> {
+        let (Some(min_x), Some(max_x)) = (
+            self.cells.keys().map(|p| p.x).min(),
+            self.cells.keys().map(|p| p.x).max(),
+        ) else {
+            return Grid::new_from_data(Vec::new());
+        };
+        let min_y = self.cells.keys().map(|p| p.y).min().unwrap();
+        let max_y = self.cells.keys().map(|p| p.y).max().unwrap();
+        let default = &default;
+
+        Grid::from_rows((min_y..=max_y).map(|y| {
+            (min_x..=max_x).map(move |x| {
+                self.cells
+                    .get(&Point2D::new(x, y))
+                    .cloned()
+                    .unwrap_or_else(|| default.clone())
+            })
+        }))
+    }
+}
+
+/// Return the eight points around `point`.
+fn surrounding(point: Point2D<i64>) -> impl Iterator<Item = Point2D<i64>> {
+    (-1..=1)
+        .flat_map(|dy| (-1..=1).map(move |dx| (dx, dy)))
+        .filter(|&offset| offset != (0, 0))
+        .map(move |(dx, dy)| Point2D::new(point.x + dx, point.y + dy))
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> FromIterator<(Point2D<i64>, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Point2D<i64>, T)>>(iter: I) -> Self {
+        SparseGrid {
+            cells: iter.into_iter().collect(),
+        }
+    }
+}
+
+/// An unbounded grid with `D` dimensions, that only stores the cells that are present, keyed on their integer coordinates.
+/// This is the `D` dimensional version of [`SparseGrid`], for automata that live in three or more dimensions (e.g. the Conway Cubes of AoC 2020 Day 17).
+///
+/// # Example:
+///
+/// ```
+/// use aoc_helper::geometry::sparse_grid::SparseGridND;
+///
+/// let mut grid = SparseGridND::new();
+///
+/// grid.insert([1, -2, 3, 0], true);
+///
+/// assert_eq!(grid.get(&[1, -2, 3, 0]), Some(&true));
+/// assert_eq!(SparseGridND::<bool, 4>::neighbor_offsets().len(), 80);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseGridND<T, const D: usize> {
+    cells: HashMap<[i64; D], T>,
+}
+
+impl<T, const D: usize> SparseGridND<T, D> {
+    /// Creates a new, empty [`SparseGridND<T, D>`].
+    pub fn new() -> SparseGridND<T, D> {
+        SparseGridND {
+            cells: HashMap::new(),
+        }
+    }
+
+    /// Store `data` at `point`. Returns the data that was previously stored there, if any.
+    pub fn insert(&mut self, point: [i64; D], data: T) -> Option<T> {
+        self.cells.insert(point, data)
+    }
+
+    /// Return a reference to the data stored at `point`, or [`None`] if the cell is not present.
+    pub fn get(&self, point: &[i64; D]) -> Option<&T> {
+        self.cells.get(point)
+    }
+
+    /// Remove the cell at `point`, and return its data.
+    pub fn remove(&mut self, point: &[i64; D]) -> Option<T> {
+        self.cells.remove(point)
+    }
+
+    /// Return the number of cells that are present.
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    /// Return `true` if no cells are present.
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Return an iterator over the present cells and their data, in arbitrary order.
+    pub fn iter(&self) -> impl Iterator<Item = (&[i64; D], &T)> {
+        self.cells.iter()
+    }
+
+    /// Return the offsets of the 3^`D` - 1 cells that surround a cell, i.e. every combination of -1, 0 and 1 in each dimension, except all zeros.
+    pub fn neighbor_offsets() -> Vec<[i64; D]> {
+        let count = 3_usize.pow(D as u32);
+
+        (0..count)
+            .map(|mut i| {
+                // Read the digits of i in base 3, and shift them from 0..=2 to -1..=1
+                let mut offset = [0; D];
+                for value in offset.iter_mut() {
+                    *value = (i % 3) as i64 - 1;
+                    i /= 3;
+                }
+                offset
+            })
+            .filter(|offset| offset.iter().any(|&v| v != 0))
+            .collect()
+    }
+
+    /// Calculate the next generation of a cellular automaton, and return it as a new grid.
+    /// This works the same way as [`SparseGrid::step_automaton`], but every cell has 3^`D` - 1 neighbors.
+    ///
+    /// # Arguments
+    ///
+    /// * `rule` - A function that receives the data of a cell ([`None`] if it is not present) and the data of its present neighbors, and returns the next state of the cell ([`None`] to leave it out).
+    pub fn step_automaton<F>(&self, rule: F) -> SparseGridND<T, D>
+    where
+        F: Fn(Option<&T>, &[&T]) -> Option<T>,
+    {
+        let offsets = Self::neighbor_offsets();
+        let shift = |point: &[i64; D], offset: &[i64; D]| -> [i64; D] {
+            std::array::from_fn(|i| point[i] + offset[i])
+        };
+
+        let candidates: HashSet<_> = self
+            .cells
+            .keys()
+            .flat_map(|point| {
+                offsets
+                    .iter()
+                    .map(|offset| shift(point, offset))
+                    .chain(std::iter::once(*point))
+            })
+            .collect();
+
+        let cells = candidates
+            .into_iter()
+            .filter_map(|point| {
+                let neighbors = offsets
+                    .iter()
+                    .filter_map(|offset| self.cells.get(&shift(&point, offset)))
+                    .collect::<Vec<_>>();
+
+                rule(self.cells.get(&point), &neighbors).map(|data| (point, data))
+            })
+            .collect();
+
+        SparseGridND { cells }
+    }
+}
+
+impl<T, const D: usize> Default for SparseGridND<T, D> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const D: usize> FromIterator<([i64; D], T)> for SparseGridND<T, D> {
+    fn from_iter<I: IntoIterator<Item = ([i64; D], T)>>(iter: I) -> Self {
+        SparseGridND {
+            cells: iter.into_iter().collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::graph::Graph;
+
+    use super::*;
+
+    #[test]
+    fn to_grid_fills_gaps() {
+        let sparse: SparseGrid<u8> = [
+            (Point2D::new(-2, -1), 1),
+            (Point2D::new(2, -1), 2),
+            (Point2D::new(0, 3), 3),
+        ]
+        .into_iter()
+        .collect();
+
+        let grid = sparse.to_grid(0);
+        let data = grid.to_vec();
+
+        assert_eq!(data.len(), 5);
+        assert!(data.iter().all(|row| row.len() == 5));
+        assert_eq!(data[0], vec![1, 0, 0, 0, 2]);
+        assert_eq!(data[4][2], 3);
+        assert_eq!(data[2][2], 0);
+        assert_eq!(grid.find_nodes(|&v| v != 0).len(), 3);
+
+        assert!(SparseGrid::<u8>::new().to_grid(0).to_vec().is_empty());
+    }
+
+    #[test]
+    fn insert_get_remove_work() {
+        let mut grid = SparseGrid::new();
+
+        assert_eq!(grid.insert(Point2D::new(1, 2), 'a'), None);
+        assert_eq!(grid.insert(Point2D::new(1, 2), 'b'), Some('a'));
+        grid.insert(Point2D::new(-100, 1_000_000), 'c');
+
+        assert_eq!(grid.len(), 2);
+        assert_eq!(grid.get(&Point2D::new(1, 2)), Some(&'b'));
+        assert_eq!(grid.remove(&Point2D::new(-100, 1_000_000)), Some('c'));
+        assert_eq!(grid.remove(&Point2D::new(0, 0)), None);
+        assert_eq!(
+            grid.iter().collect::<Vec<_>>(),
+            vec![(&Point2D::new(1, 2), &'b')]
+        );
+    }
+
+    #[test]
+    fn step_automaton_grows_without_bounds() {
+        // Every cell next to an active cell becomes active
+        let grow = |cell: Option<&u32>, neighbors: &[&u32]| {
+            cell.copied()
+                .or_else(|| neighbors.iter().map(|&&n| n + 1).min())
+        };
+
+        let mut grid: SparseGrid<u32> = [(Point2D::new(0, 0), 0)].into_iter().collect();
+
+        for generation in 1..=4 {
+            grid = grid.step_automaton(grow);
+
+            let side = 2 * generation + 1;
+            assert_eq!(grid.len(), side * side);
+        }
+
+        // The data records the generation a cell became active
+        assert_eq!(grid.get(&Point2D::new(-4, 4)), Some(&4));
+        assert_eq!(grid.get(&Point2D::new(1, -1)), Some(&1));
+    }
+
+    #[test]
+    fn neighbor_offsets_cover_every_dimension() {
+        assert_eq!(SparseGridND::<(), 1>::neighbor_offsets(), vec![[-1], [1]]);
+        assert_eq!(SparseGridND::<(), 2>::neighbor_offsets().len(), 8);
+
+        let offsets = SparseGridND::<(), 3>::neighbor_offsets();
+        assert_eq!(offsets.len(), 26);
+        assert_eq!(offsets.iter().collect::<HashSet<_>>().len(), 26);
+        assert!(!offsets.contains(&[0, 0, 0]));
+        assert!(offsets.contains(&[-1, 1, 0]));
+    }
+
+    /// Run six cycles of the Conway Cubes from AoC 2020 Day 17, and return the number of active cubes.
+    fn conway_cubes<const D: usize>() -> usize {
+        let input = ".#.\n..#\n###";
+
+        let mut grid: SparseGridND<(), D> = input
+            .lines()
+            .enumerate()
+            .flat_map(|(y, line)| {
+                line.chars()
+                    .enumerate()
+                    .filter(|&(_, c)| c == '#')
+                    .map(move |(x, _)| {
+                        let mut point = [0; D];
+                        point[0] = x as i64;
+                        point[1] = y as i64;
+                        (point, ())
+                    })
+            })
+            .collect();
+
+        for _ in 0..6 {
+            grid = grid.step_automaton(|cell, neighbors| match (cell, neighbors.len()) {
+                (Some(_), 2 | 3) | (None, 3) => Some(()),
+                _ => None,
+            });
+        }
+
+        grid.len()
+    }
+
+    #[test]
+    fn step_automaton_solves_2020_day_17() {
+        assert_eq!(conway_cubes::<3>(), 112);
+        assert_eq!(conway_cubes::<4>(), 848);
+    }
+
+    #[test]
+    fn step_automaton_moves_glider() {
+        let life = |cell: Option<&()>, neighbors: &[&()]| match (cell, neighbors.len()) {
+            (Some(_), 2 | 3) | (None, 3) => Some(()),
+            _ => None,
+        };
+
+        // .#.
+        // ..#
+        // ###
+        let glider: SparseGrid<()> = [(1, 0), (2, 1), (0, 2), (1, 2), (2, 2)]
+            .into_iter()
+            .map(|(x, y)| (Point2D::new(x, y), ()))
+            .collect();
+
+        let mut grid = glider.clone();
+        for _ in 0..4 {
+            grid = grid.step_automaton(life);
+            assert_eq!(grid.len(), 5);
+        }
+
+        // After four generations, the glider moved one cell down and to the right
+        let moved: SparseGrid<()> = glider
+            .iter()
+            .map(|(p, _)| (Point2D::new(p.x + 1, p.y + 1), ()))
+            .collect();
+        assert_eq!(grid, moved);
+    }
+}
@@ -1,16 +1,62 @@
 #![allow(dead_code)]
-use std::fmt::Display;
-
-use crate::direction::{relative_direction::RelativeDirection, Direction};
+use std::{
+    cmp::Ordering,
+    collections::{HashMap, HashSet, VecDeque},
+    fmt::Display,
+    hash::{DefaultHasher, Hash, Hasher},
+};
+
+use crate::{
+    direction::{
+        octal_direction::OctalDirection, relative_direction::RelativeDirection, Direction,
+    },
+    parse::map_tile::MapTile,
+    search::search_states,
+};
 
 use super::{vec_graph::VecGraph, EdgeIndex, Graph, GraphIntoIterator, NodeIndex};
 
 // A grid is a specialized form of a graph, where each node can connect to two (if the node is on the corners), three (if the node is on the edge), or four other nodes.
+#[derive(Clone)]
 pub struct Grid<T: Clone> {
     pub node_indices: Option<Vec<Vec<NodeIndex>>>,
     graph: VecGraph<T>,
 }
 
+/// Describes which cells of a grid are considered to be neighbors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Connectivity {
+    /// Cells that share a side (up, right, down and left).
+    Orthogonal,
+    /// Cells that only share a corner.
+    Diagonal,
+    /// Cells that share a side or a corner.
+    All,
+}
+
+impl Connectivity {
+    /// Return the directions in which the neighbors of a cell are located, starting at [`OctalDirection::North`] and moving clockwise.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use aoc_helper::{direction::octal_direction::OctalDirection, graph::grid::Connectivity};
+    ///
+    /// assert_eq!(Connectivity::All.directions().len(), 8);
+    /// assert_eq!(Connectivity::Diagonal.directions()[0], OctalDirection::NorthEast);
+    /// ```
+    pub fn directions(&self) -> Vec<OctalDirection> {
+        OctalDirection::all()
+            .into_iter()
+            .filter(|direction| match self {
+                Connectivity::Orthogonal => !direction.is_diagonal(),
+                Connectivity::Diagonal => direction.is_diagonal(),
+                Connectivity::All => true,
+            })
+            .collect()
+    }
+}
+
 impl<T: Clone> Graph for Grid<T> {
     type DataType = T;
     type NodeReference = NodeIndex;
@@ -51,6 +97,18 @@ impl<T: Clone> Graph for Grid<T> {
         self.graph.dijkstra(start, target, cost_fn)
     }
 
+    fn dijkstra_with_cost<F>(
+        &self,
+        start: Self::NodeReference,
+        target: Self::NodeReference,
+        cost_fn: F,
+    ) -> Option<(Vec<Self::NodeReference>, usize)>
+    where
+        F: Fn(&Self::DataType) -> usize,
+    {
+        self.graph.dijkstra_with_cost(start, target, cost_fn)
+    }
+
     fn dijkstra_search_with_closure<S, D, C>(
         &self,
         frontier_fn: S,
@@ -99,18 +157,90 @@ impl<T: Clone> Graph for Grid<T> {
 
 impl<T: Clone> Grid<T> {
     /// Create a new grid from a vector of vectors.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the rows do not all have the same length.
     pub fn new_from_data(data: Vec<Vec<T>>) -> Grid<T> {
-        let mut graph = VecGraph::new();
-        let mut nodes = Vec::new();
-
-        for row in 0..data.len() {
-            nodes.push(Vec::new());
+        Self::from_rows(data)
+    }
 
-            for col in 0..data[row].len() {
-                let current = graph.add_node(data[row][col].clone());
+    /// Create a new grid from a vector of vectors, surrounded by a ring of cells containing `border`.
+    /// The resulting grid is two cells taller and wider than `data`, so every cell of `data` has all four neighbors, and the cell at (`row`, `col`) in `data` is at (`row + 1`, `col + 1`) in the grid.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the rows do not all have the same length.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use aoc_helper::graph::{Graph, grid::Grid};
+    ///
+    /// let grid = Grid::new_from_data_padded(vec![vec!['.']], '#');
+    /// let n = grid.node_indices.clone().unwrap();
+    ///
+    /// assert_eq!(n.len(), 3);
+    /// assert_eq!(grid.get_data(&n[1][1]), Some(&'.'));
+    /// assert_eq!(grid.neighbor_data(n[1][1]), vec![&'#'; 4]);
+    /// ```
+    pub fn new_from_data_padded(data: Vec<Vec<T>>, border: T) -> Grid<T> {
+        let width = data.first().map_or(0, |row| row.len()) + 2;
+        let border_row = || vec![border.clone(); width];
+
+        let rows = std::iter::once(border_row())
+            .chain(data.into_iter().map(|row| {
+                let mut padded = Vec::with_capacity(row.len() + 2);
+                padded.push(border.clone());
+                padded.extend(row);
+                padded.push(border.clone());
+                padded
+            }))
+            .chain(std::iter::once(border_row()));
+
+        Self::from_rows(rows)
+    }
 
-                nodes[row].push(current);
+    /// Create a new grid from an iterator of rows, where each row is an iterator of cells.
+    /// This makes it possible to build a grid directly from e.g. the lines of the puzzle input, without collecting them first.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the rows do not all have the same length.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use aoc_helper::graph::grid::Grid;
+    ///
+    /// let input = "123\n456";
+    /// let grid = Grid::from_rows(input.lines().map(|l| l.chars()));
+    ///
+    /// assert_eq!(grid.node_indices.unwrap().len(), 2);
+    /// ```
+    pub fn from_rows<I, R>(rows: I) -> Grid<T>
+    where
+        I: IntoIterator<Item = R>,
+        R: IntoIterator<Item = T>,
+    {
+        let mut graph = VecGraph::new();
+        let mut nodes: Vec<Vec<NodeIndex>> = Vec::new();
+
+        for row in rows {
+            let row_nodes = row
+                .into_iter()
+                .map(|cell| graph.add_node(cell))
+                .collect::<Vec<_>>();
+
+            if let Some(first_row) = nodes.first() {
+                assert_eq!(
+                    first_row.len(),
+                    row_nodes.len(),
+                    "All rows in a grid must have the same length."
+                );
             }
+
+            nodes.push(row_nodes);
         }
 
         for row in 0..nodes.len() {
@@ -130,6 +260,751 @@ impl<T: Clone> Grid<T> {
         }
     }
 
+    /// Create a new grid from a map of (`row`, `col`) coordinates to cell data, e.g. one built with [`index_to_coord_map`](Grid::index_to_coord_map).
+    /// The grid starts at (0, 0), and is just large enough to contain the largest row and the largest column in `map`. Cells that are not in `map` are filled with `default`.
+    /// An empty `map` creates an empty grid.
+    ///
+    /// # Arguments
+    ///
+    /// * `map` - The data of the cells, keyed on their (`row`, `col`) coordinates.
+    /// * `default` - The data of the cells that are not in `map`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use aoc_helper::graph::grid::Grid;
+    ///
+    /// let map = HashMap::from([((0, 0), 1), ((1, 2), 2)]);
+    /// let grid = Grid::from_coord_map(map, 0);
+    ///
+    /// assert_eq!(grid.to_vec(), vec![vec![1, 0, 0], vec![0, 0, 2]]);
+    /// ```
+    pub fn from_coord_map(map: HashMap<(usize, usize), T>, default: T) -> Grid<T> {
+        let Some(height) = map.keys().map(|&(row, _)| row + 1).max() else {
+            return Grid::new_from_data(Vec::new());
+        };
+        let width = map.keys().map(|&(_, col)| col + 1).max().unwrap();
+
+        let mut data = vec![vec![default; width]; height];
+        for ((row, col), cell) in map {
+            data[row][col] = cell;
+        }
+
+        Grid::new_from_data(data)
+    }
+
+    /// Return a new grid that contains a copy of the rectangle of cells with its top left corner at (`top`, `left`), and the given `height` and `width`.
+    /// The new grid is independent of `self`, and its cells are only connected to the other cells inside the rectangle.
+    /// Returns [`None`] if the rectangle does not fit inside this grid.
+    ///
+    /// # Arguments
+    ///
+    /// * `top` - The row of the top left corner of the rectangle.
+    /// * `left` - The column of the top left corner of the rectangle.
+    /// * `height` - The number of rows in the rectangle.
+    /// * `width` - The number of columns in the rectangle.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use aoc_helper::graph::{Graph, grid::Grid};
+    ///
+    /// let grid = Grid::new_from_data(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+    ///
+    /// let sub = grid.subgrid(0, 1, 2, 2).unwrap();
+    /// let data = sub.iter().map(|n| *sub.get_data(n).unwrap()).collect::<Vec<_>>();
+    ///
+    /// assert_eq!(&data, &[2, 3, 5, 6]);
+    /// assert!(grid.subgrid(1, 1, 2, 2).is_none());
+    /// ```
+    pub fn subgrid(&self, top: usize, left: usize, height: usize, width: usize) -> Option<Grid<T>> {
+        let rows = self.node_indices.as_ref()?;
+        let (grid_height, grid_width) = self.dimensions();
+
+        if top.checked_add(height)? > grid_height || left.checked_add(width)? > grid_width {
+            return None;
+        }
+
+        Some(Grid::from_rows(rows[top..top + height].iter().map(|row| {
+            row[left..left + width]
+                .iter()
+                .map(|n| self.get_data(n).unwrap().clone())
+        })))
+    }
+
+    /// Return a copy of the data stored in the cells of this grid, as a vector of rows.
+    /// This is the inverse of [`new_from_data`](Grid::new_from_data).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use aoc_helper::graph::grid::Grid;
+    ///
+    /// let data = vec![vec![1, 2], vec![3, 4]];
+    /// let grid = Grid::new_from_data(data.clone());
+    ///
+    /// assert_eq!(grid.to_vec(), data);
+    /// ```
+    pub fn to_vec(&self) -> Vec<Vec<T>> {
+        self.node_indices
+            .as_deref()
+            .unwrap_or_default()
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|n| self.get_data(n).unwrap().clone())
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Return a copy of the data in the top row, from left to right. Returns an empty vector if the grid has no cells.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use aoc_helper::graph::grid::Grid;
+    ///
+    /// let grid = Grid::new_from_data(vec![vec![1, 2], vec![3, 4]]);
+    ///
+    /// assert_eq!(grid.top_edge(), vec![1, 2]);
+    /// ```
+    pub fn top_edge(&self) -> Vec<T> {
+        self.row_data(0)
+    }
+
+    /// Return a copy of the data in the bottom row, from left to right. Returns an empty vector if the grid has no cells.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use aoc_helper::graph::grid::Grid;
+    ///
+    /// let grid = Grid::new_from_data(vec![vec![1, 2], vec![3, 4]]);
+    ///
+    /// assert_eq!(grid.bottom_edge(), vec![3, 4]);
+    /// ```
+    pub fn bottom_edge(&self) -> Vec<T> {
+        let (height, _) = self.dimensions();
+        self.row_data(height.saturating_sub(1))
+    }
+
+    /// Return a copy of the data in the leftmost column, from top to bottom. Returns an empty vector if the grid has no cells.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use aoc_helper::graph::grid::Grid;
+    ///
+    /// let grid = Grid::new_from_data(vec![vec![1, 2], vec![3, 4]]);
+    ///
+    /// assert_eq!(grid.left_edge(), vec![1, 3]);
+    /// ```
+    pub fn left_edge(&self) -> Vec<T> {
+        self.col_data(0)
+    }
+
+    /// Return a copy of the data in the rightmost column, from top to bottom. Returns an empty vector if the grid has no cells.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use aoc_helper::graph::grid::Grid;
+    ///
+    /// let grid = Grid::new_from_data(vec![vec![1, 2], vec![3, 4]]);
+    ///
+    /// assert_eq!(grid.right_edge(), vec![2, 4]);
+    /// ```
+    pub fn right_edge(&self) -> Vec<T> {
+        let (_, width) = self.dimensions();
+        self.col_data(width.saturating_sub(1))
+    }
+
+    fn row_data(&self, row: usize) -> Vec<T> {
+        self.node_indices
+            .as_deref()
+            .and_then(|rows| rows.get(row))
+            .map(|row| {
+                row.iter()
+                    .map(|n| self.get_data(n).unwrap().clone())
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    fn col_data(&self, col: usize) -> Vec<T> {
+        self.node_indices
+            .as_deref()
+            .unwrap_or_default()
+            .iter()
+            .filter_map(|row| row.get(col))
+            .map(|n| self.get_data(n).unwrap().clone())
+            .collect()
+    }
+
+    /// Return a new grid where every row and column that only contains empty cells is replaced by `factor` copies of itself (the "cosmic expansion" from AoC 2023 Day 11).
+    /// A `factor` of 1 returns an unchanged copy, and a `factor` of 2 doubles every empty row and column.
+    /// Note that a `factor` of 0 removes the empty rows and columns.
+    ///
+    /// # Arguments
+    ///
+    /// * `is_empty` - A closure that returns `true` if a cell is empty.
+    /// * `factor` - The number of rows (or columns) that each empty row (or column) is replaced with.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use aoc_helper::graph::grid::Grid;
+    ///
+    /// let grid = Grid::new_from_data(vec![vec![1, 0], vec![0, 0]]);
+    /// let expanded = grid.expand_empty(|&c| c == 0, 2);
+    ///
+    /// assert_eq!(expanded.to_vec(), vec![vec![1, 0, 0], vec![0, 0, 0], vec![0, 0, 0]]);
+    /// ```
+    pub fn expand_empty<F>(&self, is_empty: F, factor: usize) -> Grid<T>
+    where
+        F: Fn(&T) -> bool,
+    {
+        let data = self.to_vec();
+        let width = data.first().map_or(0, |row| row.len());
+
+        let empty_cols = (0..width)
+            .filter(|&col| data.iter().all(|row| is_empty(&row[col])))
+            .collect::<HashSet<_>>();
+
+        let expand_row = |row: &Vec<T>| {
+            row.iter()
+                .enumerate()
+                .flat_map(|(col, cell)| {
+                    let copies = if empty_cols.contains(&col) { factor } else { 1 };
+                    std::iter::repeat_n(cell.clone(), copies)
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let rows = data.iter().flat_map(|row| {
+            let copies = if row.iter().all(&is_empty) { factor } else { 1 };
+            std::iter::repeat_n(expand_row(row), copies)
+        });
+
+        Grid::from_rows(rows)
+    }
+
+    /// Return the row and column of the cell referenced by `node`, or [`None`] if `node` is not a cell of this grid.
+    /// The coordinates are calculated from the width of the grid, so no lookup is needed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use aoc_helper::graph::grid::Grid;
+    ///
+    /// let grid = Grid::new_from_data(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+    ///
+    /// assert_eq!(grid.coords_of(grid.last_index().unwrap()), Some((1, 2)));
+    /// ```
+    pub fn coords_of(&self, node: NodeIndex) -> Option<(usize, usize)> {
+        let (height, width) = self.dimensions();
+
+        if node.0 >= height * width {
+            return None;
+        }
+
+        Some((node.0 / width, node.0 % width))
+    }
+
+    /// Return the row and column of the first cell (in row-major order) whose data matches `predicate`, or [`None`] if there is no such cell.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use aoc_helper::graph::grid::Grid;
+    ///
+    /// let grid = Grid::from_rows("..\n.S".lines().map(|l| l.chars()));
+    ///
+    /// assert_eq!(grid.find_coords(|&c| c == 'S'), Some((1, 1)));
+    /// assert_eq!(grid.find_coords(|&c| c == 'E'), None);
+    /// ```
+    pub fn find_coords<P>(&self, predicate: P) -> Option<(usize, usize)>
+    where
+        P: Fn(&T) -> bool,
+    {
+        self.coords_of(self.find(predicate)?)
+    }
+
+    /// Return a map from the [`NodeIndex`] of each cell to its row and column.
+    /// Use this instead of calling [`coords_of`](Grid::coords_of) repeatedly when many cells have to be converted, e.g. when reconstructing a long path.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use aoc_helper::graph::grid::Grid;
+    ///
+    /// let grid = Grid::new_from_data(vec![vec![1, 2], vec![3, 4]]);
+    /// let coords = grid.index_to_coord_map();
+    ///
+    /// assert_eq!(coords[&grid.first_index().unwrap()], (0, 0));
+    /// assert_eq!(coords[&grid.last_index().unwrap()], (1, 1));
+    /// ```
+    pub fn index_to_coord_map(&self) -> HashMap<NodeIndex, (usize, usize)> {
+        self.node_indices
+            .as_deref()
+            .unwrap_or_default()
+            .iter()
+            .enumerate()
+            .flat_map(|(row, indices)| {
+                indices
+                    .iter()
+                    .enumerate()
+                    .map(move |(col, &node)| (node, (row, col)))
+            })
+            .collect()
+    }
+
+    /// Iterate over the cells of this grid in row-major order, together with the data stored in them.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use aoc_helper::graph::grid::Grid;
+    ///
+    /// let grid = Grid::new_from_data(vec![vec!['a', 'b'], vec!['c', 'd']]);
+    ///
+    /// let data = grid.iter_data().map(|(_, &c)| c).collect::<String>();
+    ///
+    /// assert_eq!(data, "abcd");
+    /// ```
+    pub fn iter_data(&self) -> impl Iterator<Item = (NodeIndex, &T)> {
+        self.graph.iter_data()
+    }
+
+    /// Return the row and column of every cell whose data matches `predicate` (e.g. all galaxies in AoC 2023 Day 11).
+    ///
+    /// # Arguments
+    ///
+    /// * `predicate` - A closure that returns `true` for the cells that should be included.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::collections::HashSet;
+    /// use aoc_helper::graph::grid::Grid;
+    ///
+    /// let grid = Grid::from_rows("#.\n.#".lines().map(|l| l.chars()));
+    ///
+    /// assert_eq!(grid.positions_of(|&c| c == '#'), HashSet::from([(0, 0), (1, 1)]));
+    /// ```
+    pub fn positions_of<P>(&self, predicate: P) -> HashSet<(usize, usize)>
+    where
+        P: Fn(&T) -> bool,
+    {
+        self.node_indices
+            .as_deref()
+            .unwrap_or_default()
+            .iter()
+            .enumerate()
+            .flat_map(|(row, indices)| {
+                indices
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, node)| self.get_data(node).is_some_and(&predicate))
+                    .map(move |(col, _)| (row, col))
+            })
+            .collect()
+    }
+
+    /// Return the cell at (`row`, `col`), where coordinates outside of the grid are clamped to the closest row and column of the grid.
+    /// Unlike indexing into [`node_indices`](Grid::node_indices), this never fails for a non-empty grid, e.g. (-1, -1) is the top left cell.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the grid has no cells.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use aoc_helper::graph::grid::Grid;
+    ///
+    /// let grid = Grid::new_from_data(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+    ///
+    /// assert_eq!(grid.clamped_index(-5, 1), grid.node_indices.as_ref().unwrap()[0][1]);
+    /// assert_eq!(grid.clamped_index(10, 10), grid.last_index().unwrap());
+    /// ```
+    pub fn clamped_index(&self, row: isize, col: isize) -> NodeIndex {
+        let (height, width) = self.dimensions();
+        assert!(height > 0 && width > 0, "The grid has no cells.");
+
+        let row = row.clamp(0, height as isize - 1) as usize;
+        let col = col.clamp(0, width as isize - 1) as usize;
+
+        self.node_indices.as_ref().unwrap()[row][col]
+    }
+
+    /// Return the number of rows and columns in this grid.
+    fn dimensions(&self) -> (usize, usize) {
+        let rows = self.node_indices.as_deref().unwrap_or_default();
+
+        (rows.len(), rows.first().map_or(0, |row| row.len()))
+    }
+
+    /// Return the cell that is `offset` (in the format (row_offset, col_offset)) away from (`row`, `col`), or [`None`] if it is outside of the grid.
+    fn index_at_offset(&self, row: usize, col: usize, offset: (i8, i8)) -> Option<NodeIndex> {
+        let (row, col) = Self::position_at_offset(row, col, offset, self.dimensions())?;
+
+        Some(self.node_indices.as_ref()?[row][col])
+    }
+
+    /// Return the position that is `offset` away from (`row`, `col`), or [`None`] if it falls outside of a grid with the given (height, width) `dimensions`.
+    fn position_at_offset(
+        row: usize,
+        col: usize,
+        (row_offset, col_offset): (i8, i8),
+        (height, width): (usize, usize),
+    ) -> Option<(usize, usize)> {
+        let row = row.checked_add_signed(row_offset as isize)?;
+        let col = col.checked_add_signed(col_offset as isize)?;
+
+        (row < height && col < width).then_some((row, col))
+    }
+
+    /// Count the cells that can be reached from `start` in at most `steps` steps, or if `exact` is `true`, in exactly `steps` steps.
+    ///
+    /// Reaching a cell in exactly `steps` steps is possible if the shortest distance to it is at most `steps`, and has the same parity as `steps`:
+    /// the remaining steps can be spent moving back and forth between the cell and one of its neighbors, which takes two steps each time.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - The cell where the walk starts.
+    /// * `steps` - The number of steps that can be taken.
+    /// * `exact` - If `true`, only cells where the walk can end after exactly `steps` steps are counted.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use aoc_helper::graph::grid::Grid;
+    ///
+    /// let grid = Grid::new_from_data(vec![vec!['.'; 5]; 5]);
+    /// let center = grid.node_indices.as_ref().unwrap()[2][2];
+    ///
+    /// assert_eq!(grid.reachable_within(center, 1, false), 5);
+    /// assert_eq!(grid.reachable_within(center, 1, true), 4);
+    /// ```
+    pub fn reachable_within(&self, start: NodeIndex, steps: usize, exact: bool) -> usize {
+        // A cell without neighbors can not step back and forth, so the walk has to end where it started
+        if exact && steps > 0 && self.get_neighbors(&start).is_empty() {
+            return 0;
+        }
+
+        let mut visited = HashSet::from([start]);
+        let mut queue = VecDeque::from([(start, 0)]);
+        let mut count = 0;
+
+        while let Some((current, distance)) = queue.pop_front() {
+            if !exact || distance % 2 == steps % 2 {
+                count += 1;
+            }
+
+            if distance == steps {
+                continue;
+            }
+
+            for neighbor in self.get_neighbors(&current) {
+                if visited.insert(neighbor) {
+                    queue.push_back((neighbor, distance + 1));
+                }
+            }
+        }
+
+        count
+    }
+
+    /// Return the cells that are enclosed by a loop of cells, like the pipe loop in AoC 2023 Day 10.
+    /// The cells are found with a scanline: each row is scanned from left to right, and every time the scan crosses a loop cell that is connected to the cell above it, it switches between outside and inside.
+    /// Loop cells are never part of the result, and the cells are returned in row-major order.
+    ///
+    /// # Arguments
+    ///
+    /// * `loop_cells` - The cells of the loop, in the order they are visited when walking around the loop. The last cell is connected to the first one.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use aoc_helper::graph::grid::Grid;
+    ///
+    /// let grid = Grid::new_from_data(vec![vec!['.'; 3]; 3]);
+    /// let n = grid.node_indices.clone().unwrap();
+    ///
+    /// // A loop going around the border of the grid
+    /// let loop_cells = [n[0][0], n[0][1], n[0][2], n[1][2], n[2][2], n[2][1], n[2][0], n[1][0]];
+    ///
+    /// assert_eq!(grid.classify_inside(&loop_cells), vec![n[1][1]]);
+    /// ```
+    pub fn classify_inside(&self, loop_cells: &[NodeIndex]) -> Vec<NodeIndex> {
+        let Some(indices) = self.node_indices.as_deref() else {
+            return Vec::new();
+        };
+
+        let len = loop_cells.len();
+        let on_loop: HashSet<_> = loop_cells.iter().collect();
+
+        // Crossing a loop cell only switches sides if the loop continues upwards from it, like the '|', 'L' and 'J' pipes
+        let connects_up: HashSet<_> = loop_cells
+            .iter()
+            .enumerate()
+            .filter(|(i, cell)| {
+                let Some((row, col)) = self.coords_of(**cell) else {
+                    return false;
+                };
+
+                let above = row.checked_sub(1).map(|r| indices[r][col]);
+                let previous = loop_cells[(i + len - 1) % len];
+                let next = loop_cells[(i + 1) % len];
+
+                above.is_some_and(|a| a == previous || a == next)
+            })
+            .map(|(_, cell)| cell)
+            .collect();
+
+        let mut inside_cells = Vec::new();
+
+        for row in indices {
+            let mut inside = false;
+
+            for cell in row {
+                if on_loop.contains(cell) {
+                    if connects_up.contains(cell) {
+                        inside = !inside;
+                    }
+                } else if inside {
+                    inside_cells.push(*cell);
+                }
+            }
+        }
+
+        inside_cells
+    }
+
+    /// Return the perimeter of a set of cells, i.e. the number of cell sides that separate a cell of the set from a cell outside of it, or from the edge of the grid.
+    /// Unlike [`trace_boundary`](Grid::trace_boundary), holes in the set count towards the perimeter, and the cells do not need to be connected.
+    ///
+    /// # Arguments
+    ///
+    /// * `cells` - The cells whose perimeter is calculated.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::collections::HashSet;
+    /// use aoc_helper::graph::grid::Grid;
+    ///
+    /// let grid = Grid::new_from_data(vec![vec!['.'; 3]; 3]);
+    /// let n = grid.node_indices.clone().unwrap();
+    ///
+    /// assert_eq!(grid.cell_set_perimeter(&HashSet::from([n[1][1]])), 4);
+    /// assert_eq!(grid.cell_set_perimeter(&HashSet::from([n[0][0], n[0][1]])), 6);
+    /// ```
+    pub fn cell_set_perimeter(&self, cells: &HashSet<NodeIndex>) -> usize {
+        cells
+            .iter()
+            .map(|&cell| {
+                // Use the coordinates instead of the edges, since edges can be added to the graph, e.g. portals
+                let inside = self
+                    .neighborhood(cell, false, false)
+                    .iter()
+                    .filter(|n| cells.contains(n))
+                    .count();
+
+                4 - inside
+            })
+            .sum()
+    }
+
+    /// Trace the outline of a region of cells, and return the corners of the outline in clockwise order, starting from the top left corner.
+    /// The cell at (`row`, `col`) is treated as a unit square, with its top left corner at (`row`, `col`) and its bottom right corner at (`row + 1`, `col + 1`), so the corners can be turned into the vertices of a [`Polygon`](crate::geometry::polygon::Polygon).
+    /// Only the outer boundary is traced, holes in the region are ignored. If parts of the region only touch at a corner, the outline goes through that corner twice.
+    /// Returns an empty vector if `region` is empty.
+    ///
+    /// # Arguments
+    ///
+    /// * `region` - The cells of the region. They should be connected orthogonally, otherwise only the part containing the top left cell is traced.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use aoc_helper::graph::grid::Grid;
+    ///
+    /// let grid = Grid::new_from_data(vec![vec!['.'; 3]; 3]);
+    /// let n = grid.node_indices.clone().unwrap();
+    ///
+    /// // An L shaped region
+    /// let region = [n[0][0], n[1][0], n[1][1]];
+    ///
+    /// assert_eq!(
+    ///     grid.trace_boundary(&region),
+    ///     vec![(0, 0), (0, 1), (1, 1), (1, 2), (2, 2), (2, 0)]
+    /// );
+    /// ```
+    pub fn trace_boundary(&self, region: &[NodeIndex]) -> Vec<(usize, usize)> {
+        let cells: HashSet<_> = region.iter().filter_map(|&n| self.coords_of(n)).collect();
+
+        // Collect the sides of the cells that are not shared with another cell of the region, directed so that the region is on their right
+        let mut outgoing: HashMap<(usize, usize), Vec<(usize, usize)>> = HashMap::new();
+
+        for &(row, col) in &cells {
+            let outside = |r: Option<usize>, c: Option<usize>| {
+                r.zip(c).is_none_or(|pos| !cells.contains(&pos))
+            };
+
+            let sides = [
+                (
+                    outside(row.checked_sub(1), Some(col)),
+                    (row, col),
+                    (row, col + 1),
+                ),
+                (
+                    outside(Some(row), Some(col + 1)),
+                    (row, col + 1),
+                    (row + 1, col + 1),
+                ),
+                (
+                    outside(Some(row + 1), Some(col)),
+                    (row + 1, col + 1),
+                    (row + 1, col),
+                ),
+                (
+                    outside(Some(row), col.checked_sub(1)),
+                    (row + 1, col),
+                    (row, col),
+                ),
+            ];
+
+            for (is_boundary, from, to) in sides {
+                if is_boundary {
+                    outgoing.entry(from).or_default().push(to);
+                }
+            }
+        }
+
+        let Some(&start) = cells.iter().min() else {
+            return Vec::new();
+        };
+
+        let heading = |from: (usize, usize), to: (usize, usize)| {
+            (
+                to.0 as isize - from.0 as isize,
+                to.1 as isize - from.1 as isize,
+            )
+        };
+
+        let mut vertices = vec![start];
+        let mut current = start;
+        let mut direction = (0, 1);
+
+        loop {
+            let options = outgoing.get_mut(&current).unwrap();
+
+            // Where the region touches itself at a corner, keep turning left to stay on the outer boundary
+            let (left, straight) = ((-direction.1, direction.0), direction);
+            let chosen = options
+                .iter()
+                .position(|&next| heading(current, next) == left)
+                .or_else(|| {
+                    options
+                        .iter()
+                        .position(|&next| heading(current, next) == straight)
+                })
+                .unwrap_or(0);
+
+            let next = options.swap_remove(chosen);
+            direction = heading(current, next);
+            current = next;
+
+            if current == start {
+                break;
+            }
+
+            vertices.push(current);
+        }
+
+        // Only keep the vertices where the outline turns
+        let len = vertices.len();
+        (0..len)
+            .filter(|&i| {
+                let previous = vertices[(i + len - 1) % len];
+                let next = vertices[(i + 1) % len];
+
+                heading(previous, vertices[i]) != heading(vertices[i], next)
+            })
+            .map(|i| vertices[i])
+            .collect()
+    }
+
+    /// Create a weighted graph from a vector of vectors, where each cell is connected to its neighbors according to `connectivity`.
+    /// The weight of an edge is calculated from the cell the edge points to, so the result can be searched with [`VecGraph::dijkstra_weighted`].
+    /// The nodes are added in row-major order, so the cell at (`row`, `col`) has the index `row * width + col`.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - The rows of the grid.
+    /// * `weight_fn` - A function that calculates the weight of moving into a cell, given its data.
+    /// * `connectivity` - Decides which cells are neighbors.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the rows do not all have the same length.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use aoc_helper::graph::{NodeIndex, grid::{Connectivity, Grid}};
+    ///
+    /// let graph = Grid::weighted_graph(vec![vec![1, 9], vec![9, 1]], |&v: &u32| v, Connectivity::All);
+    ///
+    /// assert_eq!(graph.dijkstra_weighted(NodeIndex(0), NodeIndex(3)), Some((vec![NodeIndex(0), NodeIndex(3)], 1)));
+    /// ```
+    pub fn weighted_graph<W, F>(
+        data: Vec<Vec<T>>,
+        weight_fn: F,
+        connectivity: Connectivity,
+    ) -> VecGraph<T, W>
+    where
+        F: Fn(&T) -> W,
+    {
+        let height = data.len();
+        let width = data.first().map_or(0, |row| row.len());
+
+        assert!(
+            data.iter().all(|row| row.len() == width),
+            "All rows in a grid must have the same length."
+        );
+
+        let mut graph = VecGraph::default();
+        for cell in data.into_iter().flatten() {
+            graph.push_node(cell);
+        }
+
+        let directions = connectivity.directions();
+
+        for row in 0..height {
+            for col in 0..width {
+                for direction in &directions {
+                    let Some((n_row, n_col)) =
+                        Self::position_at_offset(row, col, direction.get_offset(), (height, width))
+                    else {
+                        continue;
+                    };
+
+                    let target = NodeIndex(n_row * width + n_col);
+                    let weight = weight_fn(graph.node_data(&target).unwrap());
+
+                    graph.add_weighted_edge(NodeIndex(row * width + col), target, weight);
+                }
+            }
+        }
+
+        graph
+    }
+
     /// Return the first [`NodeIndex`], if it exists.
     pub fn first_index(&self) -> Option<NodeIndex> {
         if let Some(indices) = &self.node_indices {
@@ -158,10 +1033,861 @@ impl<T: Clone> Grid<T> {
         }
     }
 
+    /// Return an iterator over the node references in this grid, in column-major order.
+    /// The first column is yielded from top to bottom, then the second column, and so on.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use aoc_helper::graph::{Graph, grid::Grid};
+    ///
+    /// let grid = Grid::new_from_data(vec![vec![1, 2], vec![3, 4]]);
+    ///
+    /// let data = grid.iter_col_major().map(|n| *grid.get_data(n).unwrap()).collect::<Vec<_>>();
+    ///
+    /// assert_eq!(&data, &[1, 3, 2, 4]);
+    /// ```
+    pub fn iter_col_major(&self) -> impl Iterator<Item = &NodeIndex> {
+        let rows = self.node_indices.as_deref().unwrap_or_default();
+        let width = rows.first().map_or(0, |row| row.len());
+
+        (0..width).flat_map(move |col| rows.iter().map(move |row| &row[col]))
+    }
+
+    /// Slide every `movable` cell as far as possible in `direction`, until it hits a `blocking` cell, the edge of the grid, or another movable cell that has already been moved.
+    /// Moving a cell swaps its data with the data of the cell it moves into, so cells that are neither movable nor blocking act as empty space.
+    ///
+    /// # Arguments
+    ///
+    /// * `direction` - The direction the movable cells slide in.
+    /// * `movable` - A closure that decides if a cell can move.
+    /// * `blocking` - A closure that decides if a cell stops movement.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use aoc_helper::{direction::relative_direction::RelativeDirection, graph::{Graph, grid::Grid}};
+    ///
+    /// let mut grid = Grid::new_from_data(vec![vec!['.', 'O', '#', 'O']]);
+    ///
+    /// grid.shift_all(RelativeDirection::Left, |&c| c == 'O', |&c| c == '#');
+    ///
+    /// let row = grid.iter().map(|n| *grid.get_data(n).unwrap()).collect::<String>();
+    /// assert_eq!(row, "O.#O");
+    /// ```
+    pub fn shift_all<M, B>(&mut self, direction: RelativeDirection, movable: M, blocking: B)
+    where
+        M: Fn(&T) -> bool,
+        B: Fn(&T) -> bool,
+    {
+        let Some(rows) = &self.node_indices else {
+            return;
+        };
+
+        let height = rows.len();
+        let width = rows.first().map_or(0, |row| row.len());
+
+        // Each line is ordered starting from the edge the cells move towards.
+        let lines: Vec<Vec<NodeIndex>> = match direction {
+            RelativeDirection::Up => (0..width)
+                .map(|col| (0..height).map(|row| rows[row][col]).collect())
+                .collect(),
+            RelativeDirection::Down => (0..width)
+                .map(|col| (0..height).rev().map(|row| rows[row][col]).collect())
+                .collect(),
+            RelativeDirection::Left => rows.clone(),
+            RelativeDirection::Right => rows
+                .iter()
+                .map(|row| row.iter().rev().copied().collect())
+                .collect(),
+        };
+
+        for line in lines {
+            let mut free = 0; // The position the next movable cell will end up at
+
+            for current in 0..line.len() {
+                let data = self.get_data(&line[current]).unwrap();
+
+                if blocking(data) {
+                    free = current + 1;
+                } else if movable(data) {
+                    if free != current {
+                        self.swap(line[free], line[current]);
+                    }
+                    free += 1;
+                }
+            }
+        }
+    }
+
+    /// Flip the data of this grid vertically, so the first row becomes the last one.
+    /// Only the data is moved: the [`NodeIndex`] of each cell, and the connections between cells stay the same.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use aoc_helper::graph::grid::Grid;
+    ///
+    /// let mut grid = Grid::new_from_data(vec![vec![1, 2], vec![3, 4]]);
+    /// grid.reverse_rows();
+    ///
+    /// assert_eq!(grid.to_vec(), vec![vec![3, 4], vec![1, 2]]);
+    /// ```
+    pub fn reverse_rows(&mut self) {
+        let Some(rows) = self.node_indices.clone() else {
+            return;
+        };
+
+        for (top, bottom) in rows.iter().zip(rows.iter().rev()).take(rows.len() / 2) {
+            for (&a, &b) in top.iter().zip(bottom) {
+                self.swap(a, b);
+            }
+        }
+    }
+
+    /// Flip the data of this grid horizontally, so the first column becomes the last one.
+    /// Only the data is moved: the [`NodeIndex`] of each cell, and the connections between cells stay the same.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use aoc_helper::graph::grid::Grid;
+    ///
+    /// let mut grid = Grid::new_from_data(vec![vec![1, 2], vec![3, 4]]);
+    /// grid.reverse_columns();
+    ///
+    /// assert_eq!(grid.to_vec(), vec![vec![2, 1], vec![4, 3]]);
+    /// ```
+    pub fn reverse_columns(&mut self) {
+        let Some(rows) = self.node_indices.clone() else {
+            return;
+        };
+
+        for row in rows {
+            for (&a, &b) in row.iter().zip(row.iter().rev()).take(row.len() / 2) {
+                self.swap(a, b);
+            }
+        }
+    }
+
+    /// Return references to the data of the cells next to `node`, in the same order as [`get_neighbors`](Graph::get_neighbors).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `node` is not a cell of this grid.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use aoc_helper::graph::grid::Grid;
+    ///
+    /// let grid = Grid::new_from_data(vec![vec![1, 2], vec![3, 4]]);
+    ///
+    /// assert_eq!(grid.neighbor_data(grid.first_index().unwrap()), vec![&2, &3]);
+    /// ```
+    pub fn neighbor_data(&self, node: NodeIndex) -> Vec<&T> {
+        self.graph.neighbor_data(node)
+    }
+
+    /// Return the cells next to `node` whose data satisfies `allowed`, in the same order as [`get_neighbors`](Graph::get_neighbors).
+    /// This makes it possible to ignore walls when searching, without building a grid that leaves them out.
+    ///
+    /// # Arguments
+    ///
+    /// * `node` - The cell whose neighbors are returned.
+    /// * `allowed` - A closure that decides if a neighbor can be entered, given its data.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use aoc_helper::graph::grid::Grid;
+    ///
+    /// let grid = Grid::new_from_data(vec![vec!['.', '#'], vec!['.', '.']]);
+    /// let n = grid.node_indices.clone().unwrap();
+    ///
+    /// assert_eq!(grid.neighbors_filtered(n[0][0], |&c| c != '#'), vec![n[1][0]]);
+    /// ```
+    pub fn neighbors_filtered<P>(&self, node: NodeIndex, allowed: P) -> Vec<NodeIndex>
+    where
+        P: Fn(&T) -> bool,
+    {
+        self.get_neighbors(&node)
+            .into_iter()
+            .filter(|n| self.get_data(n).is_some_and(&allowed))
+            .collect()
+    }
+
+    /// Return `true` if every cell of `path` is part of this grid, and every cell is a neighbor of the cell before it (as returned by [`get_neighbors`](Graph::get_neighbors)).
+    /// This is useful to check a path that was reconstructed by hand. An empty path is valid.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use aoc_helper::graph::grid::Grid;
+    ///
+    /// let grid = Grid::new_from_data(vec![vec![1, 2], vec![3, 4]]);
+    /// let n = grid.node_indices.clone().unwrap();
+    ///
+    /// assert!(grid.is_valid_path(&[n[0][0], n[0][1], n[1][1]]));
+    /// assert!(!grid.is_valid_path(&[n[0][0], n[1][1]]));
+    /// ```
+    pub fn is_valid_path(&self, path: &[NodeIndex]) -> bool {
+        path.iter().all(|&node| self.coords_of(node).is_some())
+            && path
+                .windows(2)
+                .all(|pair| self.get_neighbors(&pair[0]).contains(&pair[1]))
+    }
+
+    /// Check that every cell is connected to exactly the cells that share a side with it, i.e. a corner cell has 2 neighbors, an edge cell has 3, and an interior cell has 4.
+    /// This catches grids that were changed by hand (e.g. with [`add_edge`](Graph::add_edge)) in a way that breaks this promise.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error that names the first offending cell (in row-major order), if a cell has the wrong number of neighbors, or is connected to a cell that is not next to it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use aoc_helper::graph::{Graph, grid::Grid};
+    ///
+    /// let mut grid = Grid::new_from_data(vec![vec![1, 2], vec![3, 4]]);
+    /// assert!(grid.validate().is_ok());
+    ///
+    /// // Connect the opposite corners
+    /// grid.add_edge(grid.first_index().unwrap(), grid.last_index().unwrap());
+    /// assert!(grid.validate().is_err());
+    /// ```
+    pub fn validate(&self) -> Result<(), String> {
+        let (height, width) = self.dimensions();
+        let coords = self.index_to_coord_map();
+        let cells = self
+            .node_indices
+            .as_deref()
+            .unwrap_or_default()
+            .iter()
+            .enumerate()
+            .flat_map(|(row, nodes)| {
+                nodes
+                    .iter()
+                    .enumerate()
+                    .map(move |(col, node)| (node, (row, col)))
+            });
+
+        for (node, (row, col)) in cells {
+            let expected = [row > 0, row + 1 < height, col > 0, col + 1 < width]
+                .iter()
+                .filter(|&&b| b)
+                .count();
+            let neighbors = self.get_neighbors(node);
+
+            if neighbors.len() != expected {
+                return Err(format!(
+                    "The cell at ({row}, {col}) has {} neighbors, but it should have {expected}.",
+                    neighbors.len()
+                ));
+            }
+
+            for neighbor in neighbors {
+                let Some(&(n_row, n_col)) = coords.get(&neighbor) else {
+                    return Err(format!(
+                        "The cell at ({row}, {col}) is connected to {neighbor:?}, which is not a cell of this grid."
+                    ));
+                };
+
+                if row.abs_diff(n_row) + col.abs_diff(n_col) != 1 {
+                    return Err(format!(
+                        "The cell at ({row}, {col}) is connected to the cell at ({n_row}, {n_col}), which is not next to it."
+                    ));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Return the cells around `node`: its von Neumann neighborhood (the cells that share a side), or its Moore neighborhood (the cells that share a side or a corner) if `include_diagonals` is `true`.
+    /// The neighbors are returned clockwise, starting with the cell above `node`, and `node` itself comes first if `include_self` is `true`. Cells outside of the grid are left out.
+    /// Returns an empty vector if `node` is not a cell of this grid.
+    ///
+    /// # Arguments
+    ///
+    /// * `node` - The cell at the center of the neighborhood.
+    /// * `include_self` - Whether `node` is part of its own neighborhood.
+    /// * `include_diagonals` - Whether the cells that only share a corner with `node` are included.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use aoc_helper::graph::grid::Grid;
+    ///
+    /// let grid = Grid::new_from_data(vec![vec![1, 2], vec![3, 4]]);
+    /// let n = grid.node_indices.clone().unwrap();
+    ///
+    /// assert_eq!(grid.neighborhood(n[0][0], true, true), vec![n[0][0], n[0][1], n[1][1], n[1][0]]);
+    /// ```
+    pub fn neighborhood(
+        &self,
+        node: NodeIndex,
+        include_self: bool,
+        include_diagonals: bool,
+    ) -> Vec<NodeIndex> {
+        let Some((row, col)) = self.coords_of(node) else {
+            return Vec::new();
+        };
+
+        let connectivity = if include_diagonals {
+            Connectivity::All
+        } else {
+            Connectivity::Orthogonal
+        };
+
+        let neighbors = connectivity
+            .directions()
+            .into_iter()
+            .filter_map(|direction| self.index_at_offset(row, col, direction.get_offset()));
+
+        include_self
+            .then_some(node)
+            .into_iter()
+            .chain(neighbors)
+            .collect()
+    }
+
+    /// Count the cells around `node` whose data satisfies `predicate` (e.g. the live neighbors of a cell in Conway's Game of Life). `node` itself is not counted.
+    /// Returns 0 if `node` is not a cell of this grid.
+    ///
+    /// # Arguments
+    ///
+    /// * `node` - The cell whose neighbors are counted.
+    /// * `predicate` - A closure that decides if a neighbor is counted, given its data.
+    /// * `include_diagonals` - Whether the cells that only share a corner with `node` are counted, see [`neighborhood`](Grid::neighborhood).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use aoc_helper::graph::grid::Grid;
+    ///
+    /// let grid = Grid::new_from_data(vec![vec!['#', '.'], vec!['#', '#']]);
+    /// let n = grid.node_indices.clone().unwrap();
+    ///
+    /// assert_eq!(grid.count_neighbors(n[0][1], |&c| c == '#', false), 2);
+    /// assert_eq!(grid.count_neighbors(n[0][1], |&c| c == '#', true), 3);
+    /// ```
+    pub fn count_neighbors<P>(
+        &self,
+        node: NodeIndex,
+        predicate: P,
+        include_diagonals: bool,
+    ) -> usize
+    where
+        P: Fn(&T) -> bool,
+    {
+        self.neighborhood(node, false, include_diagonals)
+            .iter()
+            .filter(|n| self.get_data(n).is_some_and(&predicate))
+            .count()
+    }
+
+    /// Calculate the next generation of a cellular automaton (e.g. the seating system of AoC 2020 Day 11), and return it as a new grid.
+    /// Every cell is updated at the same time: `rule` always sees the data of the current generation, never a cell that has already been updated.
+    /// The neighbors are found from the coordinates of the cells, so [`Connectivity::All`] can be used even though the cells of a grid are only connected orthogonally.
+    ///
+    /// # Arguments
+    ///
+    /// * `connectivity` - Decides which cells are passed to `rule` as the neighbors of a cell.
+    /// * `rule` - A function that receives the data of a cell and of its neighbors, and returns the next state of the cell.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use aoc_helper::graph::grid::{Connectivity, Grid};
+    ///
+    /// let grid = Grid::new_from_data(vec![vec![0, 0, 1], vec![0, 0, 0]]);
+    ///
+    /// // Count the neighbors that contain a 1
+    /// let next = grid.step_automaton(Connectivity::All, |_, neighbors| neighbors.iter().filter(|&&&n| n == 1).count());
+    ///
+    /// assert_eq!(next.to_vec(), vec![vec![0, 1, 0], vec![0, 1, 1]]);
+    /// ```
+    pub fn step_automaton<F>(&self, connectivity: Connectivity, rule: F) -> Grid<T>
+    where
+        F: Fn(&T, &[&T]) -> T,
+    {
+        let offsets = connectivity
+            .directions()
+            .iter()
+            .map(|d| d.get_offset())
+            .collect::<Vec<_>>();
+
+        self.convolve(&offsets, |cell, samples| {
+            // Neighbors outside of the grid are left out
+            let neighbors = samples.iter().flatten().copied().collect::<Vec<_>>();
+
+            rule(cell, &neighbors)
+        })
+    }
+
+    /// Build a new grid by applying `f` to every cell and the cells at the `kernel` offsets around it.
+    /// Unlike [`Grid::step_automaton`], the samples are passed in the same order as `kernel`, and offsets that fall outside the grid are passed as [`None`], so `f` can tell them apart.
+    ///
+    /// # Arguments
+    ///
+    /// * `kernel` - The offsets to sample, in the format (row_offset, col_offset). `(0, 0)` samples the cell itself.
+    /// * `f` - A closure that receives the current cell and the samples, and returns the value of the cell in the new grid.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use aoc_helper::graph::grid::Grid;
+    ///
+    /// let grid = Grid::new_from_data(vec![vec![1, 2], vec![3, 4]]);
+    ///
+    /// // Count the neighbors to the right and below that are missing
+    /// let missing = grid.convolve(&[(0, 1), (1, 0)], |_, samples| samples.iter().filter(|s| s.is_none()).count());
+    ///
+    /// assert_eq!(missing.to_vec(), vec![vec![0, 1], vec![1, 2]]);
+    /// ```
+    pub fn convolve<U, F>(&self, kernel: &[(i8, i8)], f: F) -> Grid<U>
+    where
+        U: Clone,
+        F: Fn(&T, &[Option<&T>]) -> U,
+    {
+        let indices = self.node_indices.as_deref().unwrap_or_default();
+
+        let rows = indices.iter().enumerate().map(|(row, row_indices)| {
+            row_indices
+                .iter()
+                .enumerate()
+                .map(|(col, node)| {
+                    let samples = kernel
+                        .iter()
+                        .map(|&offset| self.get_data(&self.index_at_offset(row, col, offset)?))
+                        .collect::<Vec<_>>();
+
+                    f(self.get_data(node).unwrap(), &samples)
+                })
+                .collect::<Vec<_>>()
+        });
+
+        Grid::from_rows(rows)
+    }
+
+    /// Return an iterator over the cells in a straight line from `from` in `direction`, until the edge of the grid.
+    /// `from` itself is not included. The iterator is empty if `from` is not a cell of this grid.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - The cell where the ray starts.
+    /// * `direction` - The direction the ray travels in.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use aoc_helper::{direction::relative_direction::RelativeDirection, graph::grid::Grid};
+    ///
+    /// let grid = Grid::new_from_data(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+    /// let n = grid.node_indices.clone().unwrap();
+    ///
+    /// assert_eq!(grid.ray(n[1][2], RelativeDirection::Left).collect::<Vec<_>>(), vec![n[1][1], n[1][0]]);
+    /// assert_eq!(grid.ray(n[1][2], RelativeDirection::Down).count(), 0);
+    /// ```
+    pub fn ray(
+        &self,
+        from: NodeIndex,
+        direction: RelativeDirection,
+    ) -> impl Iterator<Item = NodeIndex> + '_ {
+        let offset = direction.get_offset();
+
+        std::iter::successors(self.coords_of(from).map(|_| from), move |&node| {
+            let (row, col) = self.coords_of(node)?;
+            self.index_at_offset(row, col, offset)
+        })
+        .skip(1)
+    }
+
+    /// Walk the [`ray`](Grid::ray) from `from` in `direction`, and count the cells that can be seen from `from`.
+    /// Every cell is visible until the first one where `visible` returns `false`: that cell blocks the view, but is still counted.
+    /// For example, the viewing distance of a tree in AoC 2022 Day 8 is the number of trees up to and including the first one that is at least as tall.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - The cell the view starts from.
+    /// * `direction` - The direction of the view.
+    /// * `visible` - A closure that receives the data of `from` and of a cell on the ray, and decides if the view continues past that cell.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use aoc_helper::{direction::relative_direction::RelativeDirection, graph::grid::Grid};
+    ///
+    /// let grid = Grid::new_from_data(vec![vec![5, 1, 3, 5, 2]]);
+    /// let n = grid.node_indices.clone().unwrap();
+    ///
+    /// assert_eq!(grid.count_visible_along(n[0][0], RelativeDirection::Right, |from, other| other < from), 3);
+    /// ```
+    pub fn count_visible_along<P>(
+        &self,
+        from: NodeIndex,
+        direction: RelativeDirection,
+        visible: P,
+    ) -> usize
+    where
+        P: Fn(&T, &T) -> bool,
+    {
+        let Some(origin) = self.get_data(&from) else {
+            return 0;
+        };
+
+        let mut count = 0;
+
+        for cell in self.ray(from, direction) {
+            count += 1;
+
+            if !visible(origin, self.get_data(&cell).unwrap()) {
+                break;
+            }
+        }
+
+        count
+    }
+
+    /// Exchange the data stored in cells `a` and `b`. The connections between the cells are not changed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either `a` or `b` is not a cell of this grid.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use aoc_helper::graph::grid::Grid;
+    ///
+    /// let mut grid = Grid::new_from_data(vec![vec!['@', '.']]);
+    ///
+    /// grid.swap(grid.first_index().unwrap(), grid.last_index().unwrap());
+    ///
+    /// assert_eq!(grid.to_vec(), vec![vec!['.', '@']]);
+    /// ```
+    pub fn swap(&mut self, a: NodeIndex, b: NodeIndex) {
+        self.graph.swap(a, b);
+    }
+
     /// Returns a reference to underlying graph of this [`Grid<T>`].
     pub fn get_underlying_graph(&self) -> &VecGraph<T> {
         &self.graph
     }
+
+    /// Return the minimal total cost of moving from `start` to `target`, or [`None`] if `target` cannot be reached.
+    /// The cost of a path is the sum of `cost_fn` over every cell on the path, except `start`.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - The cell where the search starts.
+    /// * `target` - The cell where the search ends.
+    /// * `cost_fn` - A function that calculates the cost of entering a cell given its data.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use aoc_helper::graph::grid::Grid;
+    ///
+    /// let grid = Grid::new_from_data(vec![vec![1, 9], vec![1, 1]]);
+    ///
+    /// let cost = grid.dijkstra_cost(grid.first_index().unwrap(), grid.last_index().unwrap(), |&v| v);
+    ///
+    /// assert_eq!(cost, Some(2));
+    /// ```
+    pub fn dijkstra_cost<F>(&self, start: NodeIndex, target: NodeIndex, cost_fn: F) -> Option<usize>
+    where
+        F: Fn(&T) -> usize,
+    {
+        self.graph
+            .dijkstra_with_cost(start, target, cost_fn)
+            .map(|(_, cost)| cost)
+    }
+
+    /// Search for the cheapest path between `start` and `target` using the A* algorithm, guided by `heuristic`, and return the path together with its cost.
+    /// As with [`dijkstra_cost`](Grid::dijkstra_cost), the cost is the sum of `cost_fn` over every cell on the path except `start`.
+    /// Returns [`None`] if `target` cannot be reached from `start`.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - The cell where the search starts.
+    /// * `target` - The cell where the search ends.
+    /// * `cost_fn` - A function that calculates the cost of entering a cell given its data.
+    /// * `heuristic` - A function that receives the (`row`, `col`) coordinates of a cell and of `target`, and estimates the cost of the remaining path. It must never overestimate the cost, otherwise the path may not be optimal.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use aoc_helper::graph::grid::Grid;
+    ///
+    /// let grid = Grid::new_from_data(vec![vec![1, 9, 1], vec![1, 1, 1]]);
+    /// let manhattan = |(r1, c1): (usize, usize), (r2, c2): (usize, usize)| r1.abs_diff(r2) + c1.abs_diff(c2);
+    ///
+    /// let (path, cost) = grid.a_star_with(grid.first_index().unwrap(), grid.last_index().unwrap(), |&v| v, manhattan).unwrap();
+    ///
+    /// assert_eq!(path.len(), 4);
+    /// assert_eq!(cost, 3);
+    /// ```
+    pub fn a_star_with<F, H>(
+        &self,
+        start: NodeIndex,
+        target: NodeIndex,
+        cost_fn: F,
+        heuristic: H,
+    ) -> Option<(Vec<NodeIndex>, usize)>
+    where
+        F: Fn(&T) -> usize,
+        H: Fn((usize, usize), (usize, usize)) -> usize,
+    {
+        let target_coords = self.coords_of(target)?;
+
+        search_states(
+            start,
+            |node| self.get_neighbors(node),
+            |node| heuristic(self.coords_of(*node).unwrap(), target_coords),
+            |_, next| cost_fn(self.get_data(next).unwrap()),
+            |node| *node == target,
+        )
+    }
+
+    /// Return an iterator over the cells of the region that contains `start`, where every cell satisfies `predicate`.
+    /// The region is explored with a breadth-first search, lazily: neighbors are only examined when the iterator is advanced, so callers can stop early without visiting the whole region.
+    /// If `start` does not satisfy `predicate`, the iterator is empty.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - The cell where the flood fill starts.
+    /// * `predicate` - A closure that decides if a cell is part of the region.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use aoc_helper::graph::{Graph, grid::Grid};
+    ///
+    /// let grid = Grid::new_from_data(vec![vec!['.', '.', '#'], vec!['#', '.', '#'], vec!['.', '#', '.']]);
+    ///
+    /// let region = grid.flood_iter(grid.first_index().unwrap(), |&c| c == '.').collect::<Vec<_>>();
+    ///
+    /// assert_eq!(region.len(), 3);
+    /// ```
+    pub fn flood_iter<'a, P>(
+        &'a self,
+        start: NodeIndex,
+        predicate: P,
+    ) -> impl Iterator<Item = NodeIndex> + 'a
+    where
+        P: Fn(&T) -> bool + 'a,
+    {
+        let mut queue = VecDeque::new();
+        let mut visited = HashSet::new();
+
+        if self.get_data(&start).is_some_and(&predicate) {
+            queue.push_back(start);
+            visited.insert(start);
+        }
+
+        std::iter::from_fn(move || {
+            let current = queue.pop_front()?;
+
+            for neighbor in self.get_neighbors(&current) {
+                if !visited.contains(&neighbor) && predicate(self.get_data(&neighbor).unwrap()) {
+                    visited.insert(neighbor);
+                    queue.push_back(neighbor);
+                }
+            }
+
+            Some(current)
+        })
+    }
+}
+
+impl Grid<MapTile> {
+    /// Create a new grid from a maze-like map, where every character is converted with [`MapTile::from_char`].
+    /// Empty lines are skipped, and the remaining lines are trimmed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the lines do not all have the same length.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use aoc_helper::{graph::grid::Grid, parse::map_tile::MapTile};
+    ///
+    /// let maze = Grid::from_map_str("#S.\n#.E");
+    ///
+    /// assert_eq!(maze.find_coords(|&t| t == MapTile::End), Some((1, 2)));
+    /// ```
+    pub fn from_map_str(s: &str) -> Grid<MapTile> {
+        Grid::from_rows(
+            s.lines()
+                .map(str::trim)
+                .filter(|line| !line.is_empty())
+                .map(|line| line.chars().map(MapTile::from_char)),
+        )
+    }
+}
+
+impl<T: Clone + PartialEq> Grid<T> {
+    /// Check if this grid has the same dimensions and the same data in every cell as `other`. The connections between the cells are not compared.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use aoc_helper::graph::grid::Grid;
+    ///
+    /// let grid = Grid::new_from_data(vec![vec![1, 2], vec![3, 4]]);
+    ///
+    /// assert!(grid.equals_data(&Grid::new_from_data(vec![vec![1, 2], vec![3, 4]])));
+    /// assert!(!grid.equals_data(&Grid::new_from_data(vec![vec![1, 2, 3, 4]])));
+    /// ```
+    pub fn equals_data(&self, other: &Grid<T>) -> bool {
+        let rows = self.node_indices.as_deref().unwrap_or_default();
+        let other_rows = other.node_indices.as_deref().unwrap_or_default();
+
+        rows.len() == other_rows.len()
+            && rows.iter().zip(other_rows).all(|(row, other_row)| {
+                row.len() == other_row.len()
+                    && row
+                        .iter()
+                        .zip(other_row)
+                        .all(|(a, b)| self.get_data(a) == other.get_data(b))
+            })
+    }
+
+    /// Repeatedly apply `step`, starting from this grid, until a step does not change the grid any more.
+    /// Returns the stable grid, and the number of steps that changed the grid.
+    /// Does not return if the grid never stabilizes (e.g. it oscillates).
+    ///
+    /// # Arguments
+    ///
+    /// * `step` - A function that calculates the next state of the whole grid.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use aoc_helper::graph::grid::Grid;
+    ///
+    /// // Each step, the rightmost '.' becomes a '#'
+    /// let grid = Grid::new_from_data(vec![vec!['.', '.', '#']]);
+    ///
+    /// let (stable, steps) = grid.step_until_stable(|g| {
+    ///     let mut row = g.to_vec().remove(0);
+    ///     if let Some(i) = row.iter().rposition(|&c| c == '.') {
+    ///         row[i] = '#';
+    ///     }
+    ///     Grid::new_from_data(vec![row])
+    /// });
+    ///
+    /// assert_eq!(stable.to_vec(), vec![vec!['#'; 3]]);
+    /// assert_eq!(steps, 2);
+    /// ```
+    pub fn step_until_stable<F>(&self, step: F) -> (Grid<T>, usize)
+    where
+        F: Fn(&Grid<T>) -> Grid<T>,
+    {
+        let mut current = self.clone();
+        let mut steps = 0;
+
+        loop {
+            let next = step(&current);
+
+            if next.equals_data(&current) {
+                return (current, steps);
+            }
+
+            current = next;
+            steps += 1;
+        }
+    }
+}
+
+impl<T: Clone + Hash> Grid<T> {
+    /// Return a hash of the dimensions of the grid, and the data of its cells in row-major order.
+    /// Storing the fingerprints of the states of a simulation in a [`HashSet`] is enough to detect when a state repeats, without keeping every grid in memory.
+    ///
+    /// Grids with the same dimensions and data always have the same fingerprint. Different grids can, very rarely, have the same fingerprint, so compare the grids themselves if a false match would be a problem.
+    /// The fingerprint is only stable within the same build of a program, so it should not be saved and compared across builds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use aoc_helper::graph::grid::Grid;
+    ///
+    /// let grid = Grid::new_from_data(vec![vec!['.', '#'], vec!['#', '.']]);
+    ///
+    /// assert_eq!(grid.fingerprint(), Grid::new_from_data(vec![vec!['.', '#'], vec!['#', '.']]).fingerprint());
+    /// assert_ne!(grid.fingerprint(), Grid::new_from_data(vec![vec!['.', '#', '#', '.']]).fingerprint());
+    /// ```
+    pub fn fingerprint(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+
+        self.dimensions().hash(&mut hasher);
+        for node in self.node_indices.iter().flatten().flatten() {
+            self.get_data(node).hash(&mut hasher);
+        }
+
+        hasher.finish()
+    }
+}
+
+impl<T: Clone + Ord> Grid<T> {
+    /// Return the row, column and data of the cell with the smallest data, or [`None`] if the grid is empty.
+    /// If there are multiple smallest cells, the first one in row-major order is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use aoc_helper::graph::grid::Grid;
+    ///
+    /// let grid = Grid::new_from_data(vec![vec![3, 1], vec![2, 1]]);
+    ///
+    /// assert_eq!(grid.min_cell(), Some((0, 1, &1)));
+    /// ```
+    pub fn min_cell(&self) -> Option<(usize, usize, &T)> {
+        self.extreme_cell(Ordering::Less)
+    }
+
+    /// Return the row, column and data of the cell with the largest data, or [`None`] if the grid is empty.
+    /// If there are multiple largest cells, the first one in row-major order is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use aoc_helper::graph::grid::Grid;
+    ///
+    /// let grid = Grid::new_from_data(vec![vec![3, 1], vec![2, 3]]);
+    ///
+    /// assert_eq!(grid.max_cell(), Some((0, 0, &3)));
+    /// ```
+    pub fn max_cell(&self) -> Option<(usize, usize, &T)> {
+        self.extreme_cell(Ordering::Greater)
+    }
+
+    /// Return the first cell in row-major order that no other cell compares as `wanted` to.
+    fn extreme_cell(&self, wanted: Ordering) -> Option<(usize, usize, &T)> {
+        let mut best: Option<(usize, usize, &T)> = None;
+
+        for (row, indices) in self
+            .node_indices
+            .as_deref()
+            .unwrap_or_default()
+            .iter()
+            .enumerate()
+        {
+            for (col, node) in indices.iter().enumerate() {
+                let data = self.get_data(node).unwrap();
+
+                if best.is_none_or(|(_, _, best_data)| data.cmp(best_data) == wanted) {
+                    best = Some((row, col, data));
+                }
+            }
+        }
+
+        best
+    }
 }
 
 impl<T: Clone + Display> Grid<T> {
@@ -210,7 +1936,65 @@ impl<T: Clone> IntoIterator for Grid<T> {
     }
 }
 
-fn get_neighbors<T>(grid: &Vec<Vec<T>>, col: usize, row: usize) -> Vec<T>
+/// Arrange a 2D layout of equally sized grids into one large grid, where `tiles[i][j]` ends up in the `i`th row and `j`th column of tiles.
+/// Cells are connected across tile boundaries, as if the large grid was created from scratch.
+///
+/// # Arguments
+///
+/// * `tiles` - The rows of tiles to assemble.
+///
+/// # Panics
+///
+/// Panics if the tiles do not all have the same dimensions, or if the rows of tiles do not all have the same length.
+///
+/// # Example
+///
+/// ```
+/// use aoc_helper::graph::{Graph, grid::{assemble, Grid}};
+///
+/// let left = Grid::new_from_data(vec![vec![1], vec![3]]);
+/// let right = Grid::new_from_data(vec![vec![2], vec![4]]);
+///
+/// let grid = assemble(vec![vec![left, right]]);
+/// let data = grid.iter().map(|n| *grid.get_data(n).unwrap()).collect::<Vec<_>>();
+///
+/// assert_eq!(&data, &[1, 2, 3, 4]);
+/// ```
+pub fn assemble<T: Clone>(tiles: Vec<Vec<Grid<T>>>) -> Grid<T> {
+    let tile_dimensions = tiles
+        .first()
+        .and_then(|row| row.first())
+        .map(|t| t.dimensions());
+    let tiles_per_row = tiles.first().map_or(0, |row| row.len());
+
+    assert!(
+        tiles.iter().all(|row| row.len() == tiles_per_row),
+        "All rows of tiles must have the same length."
+    );
+    assert!(
+        tiles
+            .iter()
+            .flatten()
+            .all(|t| Some(t.dimensions()) == tile_dimensions),
+        "All tiles must have the same dimensions."
+    );
+
+    let tile_height = tile_dimensions.map_or(0, |(height, _)| height);
+
+    let rows = tiles.iter().flat_map(|tile_row| {
+        (0..tile_height).map(move |row| {
+            tile_row.iter().flat_map(move |tile| {
+                tile.node_indices.as_ref().unwrap()[row]
+                    .iter()
+                    .map(|n| tile.get_data(n).unwrap().clone())
+            })
+        })
+    });
+
+    Grid::from_rows(rows)
+}
+
+fn get_neighbors<T>(grid: &[Vec<T>], col: usize, row: usize) -> Vec<T>
 where
     T: Clone,
 {
@@ -255,6 +2039,8 @@ where
 
 #[cfg(test)]
 pub mod test {
+    use std::cell::Cell;
+
     use crate::iter_ext::IterExt;
 
     use super::*;
@@ -270,6 +2056,1123 @@ pub mod test {
         assert!(grid.node_indices.unwrap().iter().all(|r| r.len() == 3));
     }
 
+    #[test]
+    fn new_from_data_padded_works() {
+        let grid = Grid::new_from_data_padded(vec![vec![1, 2, 3], vec![4, 5, 6]], 0);
+        let n = grid.node_indices.clone().unwrap();
+
+        assert_eq!(n.len(), 4);
+        assert!(n.iter().all(|r| r.len() == 5));
+
+        for (row, col) in [(0, 0), (0, 4), (3, 0), (3, 4)] {
+            assert_eq!(grid.get_data(&n[row][col]), Some(&0));
+        }
+
+        assert_eq!(
+            grid.to_vec(),
+            vec![
+                vec![0, 0, 0, 0, 0],
+                vec![0, 1, 2, 3, 0],
+                vec![0, 4, 5, 6, 0],
+                vec![0, 0, 0, 0, 0]
+            ]
+        );
+
+        // Every original cell has four neighbors
+        assert!((1..=2)
+            .flat_map(|row| (1..=3).map(move |col| (row, col)))
+            .all(|(row, col)| grid.get_neighbors(&n[row][col]).len() == 4));
+    }
+
+    #[test]
+    #[should_panic]
+    fn new_from_data_padded_panics_on_ragged_rows() {
+        Grid::new_from_data_padded(vec![vec![1, 2], vec![3]], 0);
+    }
+
+    #[test]
+    fn from_rows_works() {
+        let input = "123\n456\n789\n012";
+
+        let grid = Grid::from_rows(
+            input
+                .lines()
+                .map(|l| l.chars().map(|c| c.to_digit(10).unwrap())),
+        );
+
+        let indices = grid.node_indices.clone().unwrap();
+        assert_eq!(indices.len(), 4);
+        assert!(indices.iter().all(|r| r.len() == 3));
+
+        let values = grid
+            .iter()
+            .map(|n| *grid.get_data(n).unwrap())
+            .collect_vec();
+        assert_eq!(&values, &[1, 2, 3, 4, 5, 6, 7, 8, 9, 0, 1, 2]);
+
+        // The corners have two neighbors, the center cells have four
+        assert_eq!(grid.get_neighbors(&indices[0][0]).len(), 2);
+        assert_eq!(grid.get_neighbors(&indices[1][1]).len(), 4);
+    }
+
+    #[test]
+    #[should_panic]
+    fn from_rows_panics_on_jagged_rows() {
+        let _ = Grid::from_rows(vec![vec![1, 2, 3], vec![4, 5]]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn new_from_data_panics_on_jagged_rows() {
+        let _ = Grid::new_from_data(vec![vec![1, 2, 3], vec![4, 5]]);
+    }
+
+    #[test]
+    fn iter_col_major_works() {
+        let grid = Grid::new_from_data(vec![vec![(0, 0), (0, 1)], vec![(1, 0), (1, 1)]]);
+
+        let coords = grid
+            .iter_col_major()
+            .map(|n| *grid.get_data(n).unwrap())
+            .collect_vec();
+
+        assert_eq!(&coords, &[(0, 0), (1, 0), (0, 1), (1, 1)]);
+
+        let grid = Grid::new_from_data(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+        let indices = grid.iter_col_major().copied().collect_vec();
+        let node_indices = grid.node_indices.unwrap();
+
+        assert_eq!(
+            &indices,
+            &[
+                node_indices[0][0],
+                node_indices[1][0],
+                node_indices[0][1],
+                node_indices[1][1],
+                node_indices[0][2],
+                node_indices[1][2]
+            ]
+        );
+    }
+
+    #[test]
+    fn shift_all_north_works() {
+        let input = "O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....";
+
+        let expected = "OOOO.#.O..
+OO..#....#
+OO..O##..O
+O..#.OO...
+........#.
+..#....#.#
+..O..#.O.O
+..O.......
+#....###..
+#....#....";
+
+        let mut grid = Grid::from_rows(input.lines().map(|l| l.chars()));
+        grid.shift_all(RelativeDirection::Up, |&c| c == 'O', |&c| c == '#');
+
+        assert_eq!(grid_to_string(&grid), expected);
+    }
+
+    #[test]
+    fn shift_all_works_in_every_direction() {
+        let input = ".O#\nO..\n.O.";
+        let cases = [
+            (RelativeDirection::Up, "OO#\n.O.\n..."),
+            (RelativeDirection::Down, "..#\n.O.\nOO."),
+            (RelativeDirection::Left, "O.#\nO..\nO.."),
+            (RelativeDirection::Right, ".O#\n..O\n..O"),
+        ];
+
+        for (direction, expected) in cases {
+            let mut grid = Grid::from_rows(input.lines().map(|l| l.chars()));
+            grid.shift_all(direction, |&c| c == 'O', |&c| c == '#');
+
+            assert_eq!(grid_to_string(&grid), expected, "{:?}", direction);
+        }
+    }
+
+    #[test]
+    fn subgrid_works() {
+        let grid = Grid::new_from_data(vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]]);
+
+        let corner = grid.subgrid(1, 1, 2, 2).unwrap();
+        let indices = corner.node_indices.clone().unwrap();
+
+        assert_eq!(indices.len(), 2);
+        assert!(indices.iter().all(|r| r.len() == 2));
+
+        let data = corner
+            .iter()
+            .map(|n| *corner.get_data(n).unwrap())
+            .collect_vec();
+        assert_eq!(&data, &[5, 6, 8, 9]);
+
+        // Cells are only connected to cells inside the subgrid
+        assert!(indices
+            .iter()
+            .flatten()
+            .all(|n| corner.get_neighbors(n).len() == 2));
+
+        assert!(grid.subgrid(2, 0, 2, 1).is_none());
+        assert!(grid.subgrid(0, 3, 1, 1).is_none());
+        assert!(grid.subgrid(0, 0, usize::MAX, 1).is_none());
+    }
+
+    #[test]
+    fn assemble_works() {
+        let tile = |v: i32| Grid::new_from_data(vec![vec![v]]);
+
+        let grid = assemble(vec![vec![tile(1), tile(2)], vec![tile(3), tile(4)]]);
+        let indices = grid.node_indices.clone().unwrap();
+
+        assert_eq!(indices.len(), 2);
+        assert!(indices.iter().all(|r| r.len() == 2));
+
+        let data = grid
+            .iter()
+            .map(|n| *grid.get_data(n).unwrap())
+            .collect_vec();
+        assert_eq!(&data, &[1, 2, 3, 4]);
+
+        // Adjacency is rebuilt across tile boundaries
+        assert!(indices
+            .iter()
+            .flatten()
+            .all(|n| grid.get_neighbors(n).len() == 2));
+    }
+
+    #[test]
+    fn assemble_is_inverse_of_subgrid() {
+        let grid = Grid::new_from_data(
+            (0..4)
+                .map(|r| (0..6).map(|c| r * 6 + c).collect())
+                .collect(),
+        );
+
+        let tiles = (0..2)
+            .map(|r| {
+                (0..3)
+                    .map(|c| grid.subgrid(r * 2, c * 2, 2, 2).unwrap())
+                    .collect_vec()
+            })
+            .collect_vec();
+        let assembled = assemble(tiles);
+
+        let data = |g: &Grid<i32>| g.iter().map(|n| *g.get_data(n).unwrap()).collect_vec();
+        assert_eq!(data(&assembled), data(&grid));
+    }
+
+    #[test]
+    #[should_panic]
+    fn assemble_panics_on_different_tile_sizes() {
+        let small = Grid::new_from_data(vec![vec![1]]);
+        let large = Grid::new_from_data(vec![vec![1, 2]]);
+
+        assemble(vec![vec![small, large]]);
+    }
+
+    #[test]
+    fn to_vec_round_trips_data() {
+        let data = vec![vec!['a', 'b', 'c'], vec!['d', 'e', 'f']];
+
+        let grid = Grid::new_from_data(data.clone());
+        assert_eq!(grid.to_vec(), data);
+
+        let sub = grid.subgrid(0, 1, 2, 2).unwrap();
+        assert_eq!(sub.to_vec(), vec![vec!['b', 'c'], vec!['e', 'f']]);
+    }
+
+    #[test]
+    fn weighted_graph_takes_diagonal_shortcut() {
+        let data = vec![vec![1, 9, 9], vec![9, 1, 9], vec![9, 9, 1]];
+        let (start, target) = (NodeIndex(0), NodeIndex(8));
+
+        let all = Grid::weighted_graph(data.clone(), |&v: &usize| v, Connectivity::All);
+        assert_eq!(
+            all.dijkstra_weighted(start, target),
+            Some((vec![NodeIndex(0), NodeIndex(4), NodeIndex(8)], 2))
+        );
+
+        let orthogonal =
+            Grid::weighted_graph(data.clone(), |&v: &usize| v, Connectivity::Orthogonal);
+        assert_eq!(orthogonal.dijkstra_weighted(start, target).unwrap().1, 20);
+
+        let diagonal = Grid::weighted_graph(data, |&v: &usize| v, Connectivity::Diagonal);
+        assert_eq!(diagonal.dijkstra_weighted(start, target).unwrap().1, 2);
+        assert_eq!(diagonal.dijkstra_weighted(start, NodeIndex(1)), None);
+    }
+
+    #[test]
+    fn weighted_graph_connects_neighbors() {
+        let data = vec![vec!['a', 'b', 'c'], vec!['d', 'e', 'f']];
+
+        let graph = Grid::weighted_graph(data, |_| 1, Connectivity::All);
+
+        assert_eq!(graph.node_count(), 6);
+        assert_eq!(graph.successors(NodeIndex(0)).count(), 3);
+        assert_eq!(graph.successors(NodeIndex(1)).count(), 5);
+        assert_eq!(graph.node_data(&NodeIndex(4)), Some(&'e'));
+
+        let graph = Grid::weighted_graph(vec![vec![1, 2]], |&v| v * 10, Connectivity::Orthogonal);
+        assert_eq!(
+            graph.weighted_successors(NodeIndex(0)).collect_vec(),
+            vec![(NodeIndex(1), &20)]
+        );
+    }
+
+    #[test]
+    fn reverse_rows_works() {
+        let data = vec![vec![1, 2], vec![3, 4], vec![5, 6]];
+        let mut grid = Grid::new_from_data(data.clone());
+
+        grid.reverse_rows();
+        assert_eq!(grid.to_vec(), vec![vec![5, 6], vec![3, 4], vec![1, 2]]);
+
+        grid.reverse_rows();
+        assert_eq!(grid.to_vec(), data);
+    }
+
+    #[test]
+    fn reverse_columns_works() {
+        let data = vec![vec![1, 2, 3, 4], vec![5, 6, 7, 8]];
+        let mut grid = Grid::new_from_data(data.clone());
+
+        grid.reverse_columns();
+        assert_eq!(grid.to_vec(), vec![vec![4, 3, 2, 1], vec![8, 7, 6, 5]]);
+
+        // Adjacency is not affected
+        let first = grid.first_index().unwrap();
+        assert_eq!(grid.get_neighbors(&first).len(), 2);
+
+        grid.reverse_columns();
+        assert_eq!(grid.to_vec(), data);
+    }
+
+    #[test]
+    fn swap_works() {
+        let mut grid = Grid::new_from_data(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+        let indices = grid.node_indices.clone().unwrap();
+
+        grid.swap(indices[0][0], indices[1][2]);
+        assert_eq!(grid.to_vec(), vec![vec![6, 2, 3], vec![4, 5, 1]]);
+
+        grid.swap(indices[0][1], indices[1][1]);
+        assert_eq!(grid.to_vec(), vec![vec![6, 5, 3], vec![4, 2, 1]]);
+
+        // The neighbors of a cell do not change
+        assert_eq!(grid.get_neighbors(&indices[0][0]).len(), 2);
+    }
+
+    #[test]
+    fn min_and_max_cell_work() {
+        let grid = Grid::new_from_data(vec![vec![5, 3, 7], vec![2, 9, 4], vec![8, 2, 6]]);
+
+        assert_eq!(grid.max_cell(), Some((1, 1, &9)));
+        assert_eq!(grid.min_cell(), Some((1, 0, &2))); // Tie with (2, 1) resolves to the first in row-major order
+
+        let single = Grid::new_from_data(vec![vec!['x']]);
+        assert_eq!(single.min_cell(), Some((0, 0, &'x')));
+        assert_eq!(single.max_cell(), Some((0, 0, &'x')));
+
+        let empty: Grid<i32> = Grid::new_from_data(vec![]);
+        assert_eq!(empty.max_cell(), None);
+    }
+
+    #[test]
+    fn reachable_within_works() {
+        let grid = Grid::new_from_data(vec![vec![0; 11]; 11]);
+        let center = grid.node_indices.as_ref().unwrap()[5][5];
+
+        // In an open grid, the cells within n steps form a diamond of 2n(n + 1) + 1 cells
+        assert_eq!(grid.reachable_within(center, 0, false), 1);
+        assert_eq!(grid.reachable_within(center, 2, false), 13);
+        assert_eq!(grid.reachable_within(center, 3, false), 25);
+
+        // Exactly n steps: only the cells with the same parity as n
+        assert_eq!(grid.reachable_within(center, 0, true), 1);
+        assert_eq!(grid.reachable_within(center, 1, true), 4);
+        assert_eq!(grid.reachable_within(center, 2, true), 9);
+        assert_eq!(grid.reachable_within(center, 3, true), 16);
+
+        // The grid edges limit the reachable cells
+        let corner = grid.first_index().unwrap();
+        assert_eq!(grid.reachable_within(corner, 2, false), 6);
+        assert_eq!(grid.reachable_within(corner, 100, false), 121);
+        assert_eq!(grid.reachable_within(corner, 100, true), 61);
+    }
+
+    #[test]
+    fn reachable_within_exact_on_single_cell() {
+        let grid = Grid::new_from_data(vec![vec![0]]);
+        let start = grid.first_index().unwrap();
+
+        assert_eq!(grid.reachable_within(start, 2, false), 1);
+        assert_eq!(grid.reachable_within(start, 2, true), 0);
+        assert_eq!(grid.reachable_within(start, 0, true), 1);
+    }
+
+    #[test]
+    fn neighbor_data_returns_values() {
+        let grid = Grid::new_from_data(vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]]);
+        let center = grid.node_indices.as_ref().unwrap()[1][1];
+
+        // Up, Right, Down, Left
+        assert_eq!(grid.neighbor_data(center), vec![&2, &6, &8, &4]);
+
+        let expected = grid
+            .get_neighbors(&center)
+            .iter()
+            .map(|n| grid.get_data(n).unwrap())
+            .collect_vec();
+        assert_eq!(grid.neighbor_data(center), expected);
+    }
+
+    #[test]
+    fn index_to_coord_map_covers_every_cell() {
+        let grid = Grid::new_from_data(vec![vec![0; 4]; 3]);
+        let map = grid.index_to_coord_map();
+        let indices = grid.node_indices.clone().unwrap();
+
+        assert_eq!(map.len(), 12);
+        for (row, row_indices) in indices.iter().enumerate() {
+            for (col, node) in row_indices.iter().enumerate() {
+                assert_eq!(map[node], (row, col));
+                assert_eq!(grid.coords_of(*node), Some((row, col)));
+            }
+        }
+
+        assert_eq!(grid.coords_of(NodeIndex(12)), None);
+    }
+
+    #[test]
+    fn classify_inside_solves_2023_day_10() {
+        let input = "...........
+.S-------7.
+.|F-----7|.
+.||.....||.
+.||.....||.
+.|L-7.F-J|.
+.|..|.|..|.
+.L--J.L--J.
+...........";
+
+        let (grid, loop_cells) = trace_pipe_loop(input);
+        let inside = grid.classify_inside(&loop_cells);
+
+        assert_eq!(inside.len(), 4);
+        assert!(inside.iter().all(|n| grid.get_data(n) == Some(&'.')));
+
+        // Cells between two adjacent pipes are still outside
+        let input = "..........
+.S------7.
+.|F----7|.
+.||....||.
+.||....||.
+.|L-7F-J|.
+.|..||..|.
+.L--JL--J.
+..........";
+
+        let (grid, loop_cells) = trace_pipe_loop(input);
+
+        assert_eq!(grid.classify_inside(&loop_cells).len(), 4);
+    }
+
+    #[test]
+    fn classify_inside_empty_loop_returns_nothing() {
+        let grid = Grid::new_from_data(vec![vec!['.'; 3]; 3]);
+
+        assert!(grid.classify_inside(&[]).is_empty());
+    }
+
+    #[test]
+    fn neighbors_filtered_excludes_walls() {
+        let grid = Grid::new_from_data(vec![
+            vec!['.', '#', '.'],
+            vec!['.', '.', '#'],
+            vec!['#', '.', '.'],
+        ]);
+        let n = grid.node_indices.clone().unwrap();
+        let open = |&c: &char| c != '#';
+
+        assert_eq!(
+            grid.neighbors_filtered(n[1][1], open),
+            vec![n[2][1], n[1][0]]
+        );
+        assert_eq!(grid.neighbors_filtered(n[0][2], open), vec![]);
+
+        // Everything is allowed, so this is the same as get_neighbors
+        assert_eq!(
+            grid.neighbors_filtered(n[1][1], |_| true),
+            grid.get_neighbors(&n[1][1])
+        );
+    }
+
+    #[test]
+    fn trace_boundary_of_rectangle() {
+        let grid = Grid::new_from_data(vec![vec!['.'; 5]; 4]);
+        let n = grid.node_indices.clone().unwrap();
+
+        let region = (1..=2)
+            .flat_map(|row| (1..=3).map(move |col| (row, col)))
+            .map(|(row, col)| n[row][col])
+            .collect_vec();
+
+        assert_eq!(
+            grid.trace_boundary(&region),
+            vec![(1, 1), (1, 4), (3, 4), (3, 1)]
+        );
+        assert!(grid.trace_boundary(&[]).is_empty());
+    }
+
+    #[test]
+    fn trace_boundary_matches_polygon_area_and_perimeter() {
+        use crate::geometry::{point::Point2D, polygon::Polygon};
+
+        let grid = Grid::new_from_data(vec![
+            vec!['A', 'A', 'A', '.'],
+            vec!['A', '.', 'A', '.'],
+            vec!['A', 'A', 'A', 'A'],
+        ]);
+        let region = grid
+            .iter()
+            .filter(|n| grid.get_data(n) == Some(&'A'))
+            .copied()
+            .collect_vec();
+
+        let corners = grid.trace_boundary(&region);
+        assert_eq!(
+            corners,
+            vec![(0, 0), (0, 3), (2, 3), (2, 4), (3, 4), (3, 0)]
+        );
+
+        // The hole in the middle is not part of the outline
+        let polygon = Polygon::new_with_vertices(
+            corners
+                .into_iter()
+                .map(|(row, col)| Point2D::new(col as i32, row as i32))
+                .collect(),
+        );
+        assert_eq!(polygon.area(), 10.0);
+        assert_eq!(polygon.perimeter(), 14.0);
+
+        // Two cells that only touch at a corner
+        let n = grid.node_indices.clone().unwrap();
+        assert_eq!(
+            grid.trace_boundary(&[n[0][0], n[1][1]]),
+            vec![
+                (0, 0),
+                (0, 1),
+                (1, 1),
+                (1, 2),
+                (2, 2),
+                (2, 1),
+                (1, 1),
+                (1, 0)
+            ]
+        );
+    }
+
+    #[test]
+    fn ray_works() {
+        let grid = Grid::new_from_data(
+            (0..4)
+                .map(|r| (0..5).map(|c| r * 5 + c).collect())
+                .collect(),
+        );
+        let n = grid.node_indices.clone().unwrap();
+
+        let east = grid.ray(n[2][0], RelativeDirection::Right).collect_vec();
+        assert_eq!(east, n[2][1..].to_vec());
+
+        let north = grid.ray(n[3][4], RelativeDirection::Up).collect_vec();
+        assert_eq!(north, vec![n[2][4], n[1][4], n[0][4]]);
+
+        assert_eq!(grid.ray(n[0][0], RelativeDirection::Left).count(), 0);
+        assert_eq!(
+            grid.ray(NodeIndex(100), RelativeDirection::Right).count(),
+            0
+        );
+    }
+
+    #[test]
+    fn count_visible_along_solves_2022_day_8() {
+        let input = "30373
+25512
+65332
+33549
+35390";
+        let grid = Grid::from_rows(
+            input
+                .lines()
+                .map(|l| l.chars().map(|c| c.to_digit(10).unwrap())),
+        );
+        let n = grid.node_indices.clone().unwrap();
+        let lower = |from: &u32, other: &u32| other < from;
+
+        let scenic_score = |node: NodeIndex| {
+            RelativeDirection::all()
+                .into_iter()
+                .map(|d| grid.count_visible_along(node, d, lower))
+                .product::<usize>()
+        };
+
+        assert_eq!(
+            grid.count_visible_along(n[1][2], RelativeDirection::Up, lower),
+            1
+        );
+        assert_eq!(
+            grid.count_visible_along(n[1][2], RelativeDirection::Down, lower),
+            2
+        );
+        assert_eq!(scenic_score(n[1][2]), 4);
+        assert_eq!(scenic_score(n[3][2]), 8);
+        assert_eq!(grid.iter().map(|&node| scenic_score(node)).max(), Some(8));
+
+        // Edge trees see nothing in the direction of the edge
+        assert_eq!(
+            grid.count_visible_along(n[0][0], RelativeDirection::Up, lower),
+            0
+        );
+    }
+
+    #[test]
+    fn clamped_index_works() {
+        let grid = Grid::new_from_data(vec![vec![0; 4]; 3]);
+        let n = grid.node_indices.clone().unwrap();
+
+        assert_eq!(grid.clamped_index(-1, -1), n[0][0]);
+        assert_eq!(grid.clamped_index(isize::MIN, -3), n[0][0]);
+        assert_eq!(grid.clamped_index(1, 2), n[1][2]);
+        assert_eq!(grid.clamped_index(1, 100), n[1][3]);
+        assert_eq!(grid.clamped_index(isize::MAX, isize::MAX), n[2][3]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn clamped_index_panics_on_empty_grid() {
+        Grid::<u8>::new_from_data(vec![]).clamped_index(0, 0);
+    }
+
+    #[test]
+    fn cell_set_perimeter_of_l_shape() {
+        let grid = Grid::new_from_data(vec![vec!['.'; 4]; 4]);
+        let n = grid.node_indices.clone().unwrap();
+
+        // ##..
+        // #...
+        // #...
+        let l_shape = HashSet::from([n[0][0], n[0][1], n[1][0], n[2][0]]);
+        assert_eq!(grid.cell_set_perimeter(&l_shape), 10);
+
+        // A ring of 8 cells has an inner perimeter around the hole
+        let ring = (0..3)
+            .flat_map(|row| (0..3).map(move |col| (row, col)))
+            .filter(|&pos| pos != (1, 1))
+            .map(|(row, col)| n[row][col])
+            .collect();
+        assert_eq!(grid.cell_set_perimeter(&ring), 16);
+
+        assert_eq!(grid.cell_set_perimeter(&HashSet::new()), 0);
+    }
+
+    #[test]
+    fn cell_set_perimeter_ignores_extra_edges() {
+        let mut grid = Grid::new_from_data(vec![vec!['.'; 3]; 3]);
+        let n = grid.node_indices.clone().unwrap();
+
+        // A duplicate edge, and a portal between two cells that are not next to each other
+        grid.add_edge(n[1][1], n[0][1]);
+        for (a, b) in [(n[1][1], n[2][2]), (n[2][2], n[1][1])] {
+            grid.add_edge(a, b);
+        }
+
+        let cells = HashSet::from([n[0][1], n[1][0], n[1][1], n[1][2], n[2][1], n[2][2]]);
+        assert_eq!(grid.cell_set_perimeter(&cells), 12);
+        assert_eq!(
+            grid.cell_set_perimeter(&HashSet::from([n[1][1], n[2][2]])),
+            8
+        );
+    }
+
+    #[test]
+    fn a_star_with_matches_dijkstra() {
+        let grid = Grid::new_from_data(vec![
+            vec![1, 1, 6, 3, 7],
+            vec![1, 3, 8, 1, 3],
+            vec![2, 1, 3, 6, 5],
+            vec![3, 6, 9, 4, 9],
+            vec![7, 4, 6, 3, 4],
+        ]);
+        let (start, target) = (grid.first_index().unwrap(), grid.last_index().unwrap());
+
+        let dijkstra = grid.dijkstra_cost(start, target, |&v| v);
+        let zero = grid.a_star_with(start, target, |&v| v, |_, _| 0);
+        let manhattan = grid.a_star_with(
+            start,
+            target,
+            |&v| v,
+            |(r1, c1), (r2, c2)| r1.abs_diff(r2) + c1.abs_diff(c2),
+        );
+
+        assert_eq!(dijkstra, Some(24));
+        assert_eq!(zero.as_ref().map(|(_, cost)| *cost), dijkstra);
+        assert_eq!(manhattan.map(|(_, cost)| cost), dijkstra);
+
+        let path = zero.unwrap().0;
+        assert_eq!(path.first(), Some(&start));
+        assert_eq!(path.last(), Some(&target));
+
+        assert!(grid
+            .a_star_with(start, NodeIndex(100), |&v| v, |_, _| 0)
+            .is_none());
+    }
+
+    #[test]
+    fn equals_data_compares_dimensions_and_cells() {
+        let grid = Grid::new_from_data(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+
+        assert!(grid.equals_data(&grid.clone()));
+        assert!(!grid.equals_data(&Grid::new_from_data(vec![vec![1, 2, 3], vec![4, 5, 7]])));
+        assert!(!grid.equals_data(&Grid::new_from_data(vec![
+            vec![1, 2],
+            vec![3, 4],
+            vec![5, 6]
+        ])));
+        assert!(!grid.equals_data(&Grid::new_from_data(vec![vec![1, 2, 3]])));
+        assert!(Grid::<u8>::new_from_data(vec![]).equals_data(&Grid::new_from_data(vec![])));
+    }
+
+    #[test]
+    fn step_until_stable_spreads_until_filled() {
+        // A '#' spreads to its orthogonal neighbors every step, but can not cross walls
+        let grid = Grid::new_from_data(vec![
+            vec!['#', '.', '.', '.', '.'],
+            vec!['|', '|', '|', '.', '|'],
+            vec!['.', '.', '|', '.', '.'],
+        ]);
+
+        let spread = |g: &Grid<char>| {
+            Grid::new_from_data(
+                g.node_indices
+                    .as_ref()
+                    .unwrap()
+                    .iter()
+                    .map(|row| {
+                        row.iter()
+                            .map(|n| match g.get_data(n).unwrap() {
+                                '.' if g.neighbor_data(*n).contains(&&'#') => '#',
+                                &c => c,
+                            })
+                            .collect()
+                    })
+                    .collect(),
+            )
+        };
+
+        let (stable, steps) = grid.step_until_stable(spread);
+
+        assert_eq!(steps, 6);
+        assert_eq!(grid_to_string(&stable), "#####\n|||#|\n..|##");
+
+        // A grid that is already stable takes no steps
+        let (again, steps) = stable.step_until_stable(spread);
+        assert_eq!(steps, 0);
+        assert!(again.equals_data(&stable));
+    }
+
+    #[test]
+    fn step_automaton_solves_2020_day_11() {
+        let input = "L.LL.LL.LL
+LLLLLLL.LL
+L.L.L..L..
+LLLL.LL.LL
+L.LL.LL.LL
+L.LLLLL.LL
+..L.L.....
+LLLLLLLLLL
+L.LLLLLL.L
+L.LLLLL.LL";
+        let grid = Grid::from_rows(input.lines().map(|l| l.chars()));
+
+        let seat_rule = |&cell: &char, neighbors: &[&char]| {
+            let occupied = neighbors.iter().filter(|&&&n| n == '#').count();
+
+            match cell {
+                'L' if occupied == 0 => '#',
+                '#' if occupied >= 4 => 'L',
+                c => c,
+            }
+        };
+
+        let first = grid.step_automaton(Connectivity::All, seat_rule);
+        assert_eq!(grid_to_string(&first), input.replace('L', "#"));
+
+        let second = first.step_automaton(Connectivity::All, seat_rule);
+        assert_eq!(
+            grid_to_string(&second),
+            "#.LL.L#.##
+#LLLLLL.L#
+L.L.L..L..
+#LLL.LL.L#
+#.LL.LL.LL
+#.LLLL#.##
+..L.L.....
+#LLLLLLLL#
+#.LLLLLL.L
+#.#LLLL.##"
+        );
+
+        // The original grid is not changed
+        assert!(grid.equals_data(&Grid::from_rows(input.lines().map(|l| l.chars()))));
+
+        let (stable, _) =
+            grid.step_until_stable(|g| g.step_automaton(Connectivity::All, seat_rule));
+        assert_eq!(
+            stable
+                .iter()
+                .filter(|n| stable.get_data(n) == Some(&'#'))
+                .count(),
+            37
+        );
+    }
+
+    #[test]
+    fn step_automaton_orthogonal_neighbors() {
+        let grid = Grid::new_from_data(vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]]);
+
+        let sums = grid.step_automaton(Connectivity::Orthogonal, |_, neighbors| {
+            neighbors.iter().copied().sum::<i32>()
+        });
+
+        assert_eq!(
+            sums.to_vec(),
+            vec![vec![6, 9, 8], vec![13, 20, 17], vec![12, 21, 14]]
+        );
+    }
+
+    #[test]
+    fn fingerprint_detects_changed_cells() {
+        let data = vec![vec![1, 2, 3], vec![4, 5, 6]];
+        let mut grid = Grid::new_from_data(data.clone());
+
+        assert_eq!(grid.fingerprint(), Grid::new_from_data(data).fingerprint());
+
+        let before = grid.fingerprint();
+        *grid.get_data_mut(&grid.last_index().unwrap()).unwrap() = 7;
+        assert_ne!(grid.fingerprint(), before);
+
+        // Swapping two cells changes the order of the data
+        let mut swapped = Grid::new_from_data(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+        swapped.swap(NodeIndex(0), NodeIndex(1));
+        assert_ne!(swapped.fingerprint(), before);
+
+        // A repeated state can be found from its fingerprint
+        let mut seen = HashSet::new();
+        assert!(seen.insert(before));
+        assert!(!seen.insert(Grid::new_from_data(vec![vec![1, 2, 3], vec![4, 5, 6]]).fingerprint()));
+    }
+
+    #[test]
+    fn convolve_sums_four_neighbors() {
+        let grid = Grid::new_from_data(vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]]);
+        let kernel = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+
+        let sums = grid.convolve(&kernel, |_, samples| {
+            samples.iter().flatten().copied().sum::<i32>()
+        });
+
+        assert_eq!(
+            sums.to_vec(),
+            vec![vec![6, 9, 8], vec![13, 20, 17], vec![12, 21, 14]]
+        );
+
+        // Off-grid samples are kept in kernel order
+        let edges = grid.convolve(&kernel, |&cell, samples| {
+            (
+                cell,
+                samples.iter().map(Option::is_some).collect::<Vec<_>>(),
+            )
+        });
+        assert_eq!(
+            edges.get_data(&grid.first_index().unwrap()),
+            Some(&(1, vec![false, true, true, false]))
+        );
+    }
+
+    #[test]
+    fn positions_of_finds_all_matches() {
+        let input = "...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#.....";
+        let grid = Grid::from_rows(input.lines().map(|l| l.chars()));
+
+        let galaxies = grid.positions_of(|&c| c == '#');
+
+        assert_eq!(galaxies.len(), 9);
+        assert!(galaxies.contains(&(0, 3)));
+        assert!(galaxies.contains(&(6, 9)));
+        assert!(galaxies.contains(&(9, 4)));
+        assert!(!galaxies.contains(&(0, 0)));
+
+        assert!(grid.positions_of(|&c| c == 'x').is_empty());
+    }
+
+    #[test]
+    fn expand_empty_doubles_empty_rows_and_columns() {
+        let input = "...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#.....";
+        let grid = Grid::from_rows(input.lines().map(|l| l.chars()));
+
+        let expanded = grid.expand_empty(|&c| c == '.', 2);
+        let data = expanded.to_vec();
+
+        assert_eq!(data.len(), 12);
+        assert!(data.iter().all(|row| row.len() == 13));
+        assert_eq!(expanded.positions_of(|&c| c == '#').len(), 9);
+        assert_eq!(data[0].iter().collect::<String>(), "....#........");
+
+        assert!(grid.expand_empty(|&c| c == '.', 1).equals_data(&grid));
+
+        let collapsed = grid.expand_empty(|&c| c == '.', 0).to_vec();
+        assert_eq!(collapsed.len(), 8);
+        assert_eq!(collapsed[0].len(), 7);
+    }
+
+    #[test]
+    fn from_map_str_finds_start_and_end() {
+        let maze = Grid::from_map_str(
+            "
+            #######
+            #S..#.#
+            #.#...#
+            #...#E#
+            #######
+            ",
+        );
+
+        assert_eq!(maze.find_coords(|&t| t == MapTile::Start), Some((1, 1)));
+        assert_eq!(maze.find_coords(|&t| t == MapTile::End), Some((3, 5)));
+        assert_eq!(maze.positions_of(|&t| t == MapTile::Wall).len(), 23);
+
+        let start = maze.find(|&t| t == MapTile::Start).unwrap();
+        let end = maze.find(|&t| t == MapTile::End).unwrap();
+        let path = maze.a_star_with(
+            start,
+            end,
+            |&t| if t == MapTile::Wall { 1000 } else { 1 },
+            |(r1, c1), (r2, c2)| r1.abs_diff(r2) + c1.abs_diff(c2),
+        );
+        assert_eq!(path.map(|(_, cost)| cost), Some(6));
+    }
+
+    #[test]
+    fn neighborhood_combinations() {
+        let grid = Grid::new_from_data(vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]]);
+        let center = grid.node_indices.as_ref().unwrap()[1][1];
+        let data = |cells: Vec<NodeIndex>| {
+            cells
+                .iter()
+                .map(|n| *grid.get_data(n).unwrap())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            data(grid.neighborhood(center, false, false)),
+            vec![2, 6, 8, 4]
+        );
+        assert_eq!(
+            data(grid.neighborhood(center, true, false)),
+            vec![5, 2, 6, 8, 4]
+        );
+        assert_eq!(
+            data(grid.neighborhood(center, false, true)),
+            vec![2, 3, 6, 9, 8, 7, 4, 1]
+        );
+        assert_eq!(
+            data(grid.neighborhood(center, true, true)),
+            vec![5, 2, 3, 6, 9, 8, 7, 4, 1]
+        );
+
+        // Cells outside of the grid are left out
+        let corner = grid.last_index().unwrap();
+        assert_eq!(
+            data(grid.neighborhood(corner, true, true)),
+            vec![9, 6, 8, 5]
+        );
+        assert!(grid.neighborhood(NodeIndex(100), true, true).is_empty());
+    }
+
+    #[test]
+    fn edges_work() {
+        let grid = Grid::from_rows("abc\ndef\nghi".lines().map(|l| l.chars()));
+
+        assert_eq!(grid.top_edge(), vec!['a', 'b', 'c']);
+        assert_eq!(grid.bottom_edge(), vec!['g', 'h', 'i']);
+        assert_eq!(grid.left_edge(), vec!['a', 'd', 'g']);
+        assert_eq!(grid.right_edge(), vec!['c', 'f', 'i']);
+
+        // Tiles are matched by comparing an edge with a reversed edge
+        let mut flipped = grid.clone();
+        flipped.reverse_columns();
+        let mut reversed = grid.top_edge();
+        reversed.reverse();
+        assert_eq!(flipped.top_edge(), reversed);
+
+        let empty = Grid::<char>::new_from_data(Vec::new());
+        assert!(empty.top_edge().is_empty());
+        assert!(empty.right_edge().is_empty());
+    }
+
+    #[test]
+    fn iter_data_is_row_major() {
+        let grid = Grid::new_from_data(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+        let n = grid.node_indices.clone().unwrap();
+
+        assert_eq!(
+            grid.iter_data().collect::<Vec<_>>(),
+            vec![
+                (n[0][0], &1),
+                (n[0][1], &2),
+                (n[0][2], &3),
+                (n[1][0], &4),
+                (n[1][1], &5),
+                (n[1][2], &6)
+            ]
+        );
+    }
+
+    #[test]
+    fn from_coord_map_fills_gaps() {
+        let map = HashMap::from([((0, 1), 'a'), ((2, 0), 'b'), ((1, 3), 'c')]);
+
+        let grid = Grid::from_coord_map(map, '.');
+        let data = grid.to_vec();
+
+        assert_eq!(data.len(), 3);
+        assert!(data.iter().all(|row| row.len() == 4));
+        assert_eq!(data[1][1], '.');
+        assert_eq!(grid.positions_of(|&c| c != '.').len(), 3);
+        assert_eq!(grid.find_coords(|&c| c == 'c'), Some((1, 3)));
+
+        // Round trip through the coordinate map
+        let coords = grid.index_to_coord_map();
+        let map = grid
+            .iter_data()
+            .map(|(node, &c)| (coords[&node], c))
+            .collect();
+        assert!(Grid::from_coord_map(map, '.').equals_data(&grid));
+
+        assert!(Grid::from_coord_map(HashMap::new(), '.')
+            .to_vec()
+            .is_empty());
+    }
+
+    #[test]
+    fn is_valid_path_works() {
+        let grid = Grid::new_from_data(vec![vec![1, 9, 1], vec![1, 9, 1], vec![1, 1, 1]]);
+        let start = grid.first_index().unwrap();
+        let target = grid.node_indices.as_ref().unwrap()[0][2];
+
+        let (path, _) = grid.dijkstra_with_cost(start, target, |&v| v).unwrap();
+        assert_eq!(path.len(), 7);
+        assert!(grid.is_valid_path(&path));
+
+        // Skipping a cell disconnects the path
+        let mut skipped = path.clone();
+        skipped.remove(3);
+        assert!(!grid.is_valid_path(&skipped));
+
+        // The last cell of a row is not next to the first cell of the following row
+        let n = grid.node_indices.clone().unwrap();
+        assert_eq!(n[0][2].0 + 1, n[1][0].0);
+        assert!(!grid.is_valid_path(&[n[0][2], n[1][0]]));
+
+        assert!(!grid.is_valid_path(&[NodeIndex(100)]));
+        assert!(!grid.is_valid_path(&[start, start]));
+        assert!(grid.is_valid_path(&[]));
+        assert!(grid.is_valid_path(&[start]));
+    }
+
+    #[test]
+    fn count_neighbors_counts_live_cells() {
+        let grid = Grid::from_rows("#.#\n.##\n#..".lines().map(|l| l.chars()));
+        let n = grid.node_indices.clone().unwrap();
+        let alive = |&c: &char| c == '#';
+
+        // The center cell is alive, but it is not counted
+        assert_eq!(grid.count_neighbors(n[1][1], alive, true), 4);
+        assert_eq!(grid.count_neighbors(n[1][1], alive, false), 1);
+        assert_eq!(grid.count_neighbors(n[1][1], |&c| c == '.', true), 4);
+
+        assert_eq!(grid.count_neighbors(n[0][0], alive, true), 1);
+        assert_eq!(grid.count_neighbors(NodeIndex(100), alive, true), 0);
+    }
+
+    #[test]
+    fn validate_works() {
+        let data = vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]];
+        let grid = Grid::new_from_data(data.clone());
+
+        assert_eq!(grid.validate(), Ok(()));
+        assert_eq!(Grid::new_from_data(vec![vec![1, 2, 3]]).validate(), Ok(()));
+        assert_eq!(Grid::<u8>::new_from_data(Vec::new()).validate(), Ok(()));
+
+        // An extra edge gives a corner cell three neighbors
+        let mut tampered = Grid::new_from_data(data.clone());
+        let n = tampered.node_indices.clone().unwrap();
+        tampered.add_edge(n[0][0], n[2][2]);
+
+        let err = tampered.validate().unwrap_err();
+        assert!(err.contains("(0, 0)"), "{err}");
+
+        // Swapping two corners keeps the counts, but the neighbors of the corners are no longer next to them
+        let mut tampered = Grid::new_from_data(data);
+        tampered.node_indices.as_mut().unwrap()[0].swap(0, 2);
+
+        let err = tampered.validate().unwrap_err();
+        assert!(err.contains("(0, 0)") && err.contains("(1, 2)"), "{err}");
+    }
+
+    #[test]
+    fn iter_data_works_without_display() {
+        let maze = Grid::from_map_str("#S\nE.");
+
+        let tiles = maze.iter_data().map(|(_, &t)| t).collect::<Vec<_>>();
+
+        assert_eq!(
+            tiles,
+            vec![MapTile::Wall, MapTile::Start, MapTile::End, MapTile::Open]
+        );
+    }
+
     #[test]
     fn first_index_works() {
         let data = vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]];
@@ -317,6 +3220,26 @@ pub mod test {
         assert_eq!(path[4].0, 8);
     }
 
+    #[test]
+    fn dijkstra_cost_works() {
+        let data = vec![vec![1, 1, 9], vec![9, 1, 9], vec![9, 1, 1]];
+
+        let grid = Grid::new_from_data(data);
+
+        let start = grid.first_index().unwrap();
+        let goal = grid.last_index().unwrap();
+
+        let path = grid.dijkstra(start, goal, |&v| v);
+        let expected = path[1..]
+            .iter()
+            .map(|n| *grid.get_data(n).unwrap())
+            .sum::<usize>();
+
+        assert_eq!(grid.dijkstra_cost(start, goal, |&v| v), Some(expected));
+        assert_eq!(grid.dijkstra_cost(start, goal, |&v| v), Some(4));
+        assert_eq!(grid.dijkstra_cost(start, start, |&v| v), Some(0));
+    }
+
     #[test]
     fn can_use_iter() {
         let data = vec![vec![1, 1, 9], vec![9, 1, 9], vec![9, 1, 1]];
@@ -348,4 +3271,119 @@ pub mod test {
         assert_eq!(indices.len(), 4);
         assert_eq!(&indices.iter().map(|i| i.0).collect_vec(), &[0, 1, 2, 3]);
     }
+
+    #[test]
+    fn flood_iter_yields_region() {
+        let data = vec![
+            vec!['.', '.', '#', '.'],
+            vec!['#', '.', '#', '.'],
+            vec!['.', '.', '#', '.'],
+        ];
+
+        let grid = Grid::new_from_data(data);
+
+        let region = grid
+            .flood_iter(grid.first_index().unwrap(), |&c| c == '.')
+            .collect_vec();
+
+        assert_eq!(region.len(), 5);
+        assert_eq!(region[0], grid.first_index().unwrap());
+        assert!(region.iter().all(|n| *grid.get_data(n).unwrap() == '.'));
+
+        let walls = grid.flood_iter(grid.first_index().unwrap(), |&c| c == '#');
+        assert_eq!(walls.count(), 0);
+    }
+
+    #[test]
+    fn flood_iter_is_lazy() {
+        let data = vec![vec![0; 10]; 10];
+        let grid = Grid::new_from_data(data);
+
+        let calls = Cell::new(0);
+        let predicate = |_: &i32| {
+            calls.set(calls.get() + 1);
+            true
+        };
+
+        let first = grid
+            .flood_iter(grid.first_index().unwrap(), predicate)
+            .take(3)
+            .collect_vec();
+
+        assert_eq!(first.len(), 3);
+        assert!(calls.get() < 10);
+    }
+
+    /// Parse a pipe maze from AoC 2023 Day 10, and walk the loop that starts at 'S'.
+    fn trace_pipe_loop(input: &str) -> (Grid<char>, Vec<NodeIndex>) {
+        let data = input.lines().map(|l| l.chars().collect_vec()).collect_vec();
+
+        let connections = |c: char| -> &[(isize, isize)] {
+            match c {
+                '|' => &[(-1, 0), (1, 0)],
+                '-' => &[(0, -1), (0, 1)],
+                'L' => &[(-1, 0), (0, 1)],
+                'J' => &[(-1, 0), (0, -1)],
+                '7' => &[(1, 0), (0, -1)],
+                'F' => &[(1, 0), (0, 1)],
+                _ => &[],
+            }
+        };
+        let step = |(row, col): (usize, usize), (dr, dc): (isize, isize)| {
+            (row.wrapping_add_signed(dr), col.wrapping_add_signed(dc))
+        };
+        let char_at = |(row, col): (usize, usize)| {
+            data.get(row)
+                .and_then(|r| r.get(col))
+                .copied()
+                .unwrap_or('.')
+        };
+
+        let start = (0..data.len())
+            .flat_map(|row| (0..data[row].len()).map(move |col| (row, col)))
+            .find(|&pos| char_at(pos) == 'S')
+            .unwrap();
+
+        let mut direction = [(-1, 0), (0, 1), (1, 0), (0, -1)]
+            .into_iter()
+            .find(|&(dr, dc)| connections(char_at(step(start, (dr, dc)))).contains(&(-dr, -dc)))
+            .unwrap();
+
+        let mut positions = vec![start];
+        let mut current = step(start, direction);
+
+        while current != start {
+            positions.push(current);
+
+            let came_from = (-direction.0, -direction.1);
+            direction = *connections(char_at(current))
+                .iter()
+                .find(|&&d| d != came_from)
+                .unwrap();
+            current = step(current, direction);
+        }
+
+        let grid = Grid::new_from_data(data.clone());
+        let indices = grid.node_indices.clone().unwrap();
+        let loop_cells = positions
+            .into_iter()
+            .map(|(row, col)| indices[row][col])
+            .collect();
+
+        (grid, loop_cells)
+    }
+
+    fn grid_to_string(grid: &Grid<char>) -> String {
+        grid.node_indices
+            .as_ref()
+            .unwrap()
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|n| *grid.get_data(n).unwrap())
+                    .collect::<String>()
+            })
+            .collect_vec()
+            .join("\n")
+    }
 }
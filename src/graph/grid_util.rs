@@ -0,0 +1,82 @@
+/// Compress a path of (`row`, `col`) coordinates (e.g. a path found on a [`Grid`](super::grid::Grid), converted with [`coords_of`](super::grid::Grid::coords_of)) by collapsing every straight run of cells into its two endpoints.
+/// The result contains the first cell, every cell where the path changes direction, and the last cell, so the number of turns is the length of the result minus 2.
+/// Cells that repeat the previous cell are dropped.
+///
+/// # Arguments
+///
+/// * `path` - The cells of the path, in order.
+///
+/// # Example
+///
+/// ```
+/// use aoc_helper::graph::grid_util::compress_straight;
+///
+/// let path = [(0, 0), (0, 1), (0, 2), (1, 2), (2, 2)];
+///
+/// assert_eq!(compress_straight(&path), vec![(0, 0), (0, 2), (2, 2)]);
+/// ```
+pub fn compress_straight(path: &[(usize, usize)]) -> Vec<(usize, usize)> {
+    let mut waypoints: Vec<(usize, usize)> = Vec::new();
+    let mut last_direction = None;
+
+    for &cell in path {
+        let Some(&previous) = waypoints.last() else {
+            waypoints.push(cell);
+            continue;
+        };
+
+        let direction = step_direction(previous, cell);
+        if direction == (0, 0) {
+            continue;
+        }
+
+        if last_direction == Some(direction) {
+            // Still moving in the same direction, so the previous waypoint is in the middle of a straight run
+            *waypoints.last_mut().unwrap() = cell;
+        } else {
+            waypoints.push(cell);
+            last_direction = Some(direction);
+        }
+    }
+
+    waypoints
+}
+
+/// Return the direction of the step from `from` to `to`, as the sign of the (row, col) difference.
+fn step_direction(from: (usize, usize), to: (usize, usize)) -> (i8, i8) {
+    let sign = |a: usize, b: usize| b.cmp(&a) as i8;
+
+    (sign(from.0, to.0), sign(from.1, to.1))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn compress_straight_keeps_turns() {
+        let path = [(2, 0), (2, 1), (2, 2), (2, 3), (1, 3), (0, 3)];
+
+        assert_eq!(compress_straight(&path), vec![(2, 0), (2, 3), (0, 3)]);
+    }
+
+    #[test]
+    fn compress_straight_edge_cases() {
+        assert!(compress_straight(&[]).is_empty());
+        assert_eq!(compress_straight(&[(1, 1)]), vec![(1, 1)]);
+        assert_eq!(
+            compress_straight(&[(0, 0), (0, 0), (0, 1), (0, 2)]),
+            vec![(0, 0), (0, 2)]
+        );
+
+        // Every step is a turn
+        let stairs = [(0, 0), (0, 1), (1, 1), (1, 2), (2, 2)];
+        assert_eq!(compress_straight(&stairs), stairs.to_vec());
+
+        // Going back the same way is a turn
+        assert_eq!(
+            compress_straight(&[(0, 0), (0, 1), (0, 2), (0, 1)]),
+            vec![(0, 0), (0, 2), (0, 1)]
+        );
+    }
+}
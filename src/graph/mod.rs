@@ -1,11 +1,11 @@
-use std::{collections::HashMap, hash::Hash};
+use std::{collections::HashMap, error::Error, fmt::Display, hash::Hash};
 
 use priority_queue::DoublePriorityQueue;
 
 // Represents a set of nodes connected by edges
 pub trait Graph: IntoIterator {
     type DataType; // Type of the data contained in each node
-    type NodeReference: Hash + Eq + Clone + Copy;
+    type NodeReference: Hash + Eq + Clone;
     type EdgeReference;
 
     /// Create a new Graph.
@@ -145,35 +145,99 @@ pub trait Graph: IntoIterator {
         F: Fn(&Self::DataType) -> usize,
         Self: Sized,
     {
-        let mut frontier = DoublePriorityQueue::new();
-        frontier.push(start, 0);
-
-        let mut came_from = HashMap::new();
-        came_from.insert(start, start);
-
-        let mut cost_so_far = HashMap::new();
-        cost_so_far.insert(start, 0);
-
-        while !frontier.is_empty() {
-            let (current, _) = frontier.pop_min().unwrap();
-
-            if current == target {
-                break;
-            }
-
-            for next in self.get_neighbors(&current) {
-                let data = self.get_data(&next).unwrap();
-                let new_cost = cost_fn(data) + cost_so_far[&current];
+        self.dijkstra_with_cost(start, target, cost_fn)
+            .map(|(path, _)| path)
+            .unwrap_or_default()
+    }
 
-                if !cost_so_far.contains_key(&next) || new_cost < cost_so_far[&next] {
-                    cost_so_far.insert(next, new_cost);
-                    came_from.insert(next, current);
-                    frontier.push(next, new_cost);
-                }
-            }
-        }
+    /// Search the graph for the shortest path between `start` and `target`, using Dijkstra’s Algorithm, and return the path together with its total cost.
+    /// The total cost is the sum of `cost_fn` over every node on the path, except `start`.
+    /// The costs are added with [`usize::saturating_add`], so `cost_fn` can return [`usize::MAX`] for nodes that should be avoided, without the total wrapping around to a small value.
+    /// Returns [`None`] if `target` cannot be reached from `start`.
+    ///
+    /// # Arguments
+    ///
+    /// * `start`       - The node where the search starts.
+    /// * `target`      - The target node, where the search will terminate.
+    /// * `cost_fn`     - A function that calculates the cost of traversing given the data stored in a node.
+    ///
+    /// # Example
+    /// ```
+    /// use aoc_helper::graph::{Graph, vec_graph::VecGraph};
+    ///
+    /// let mut graph = VecGraph::new();
+    ///
+    /// let start = graph.add_node(0);
+    /// let n1 = graph.add_node(1000);
+    /// let n2 = graph.add_node(1);
+    /// let destination = graph.add_node(3);
+    ///
+    /// graph.add_edge(start, n1);
+    /// graph.add_edge(start, n2);
+    /// graph.add_edge(n1, destination);
+    /// graph.add_edge(n2, destination);
+    ///
+    /// let (path, cost) = graph.dijkstra_with_cost(start, destination, |&d| d).unwrap();
+    ///
+    /// assert_eq!(&path, &[start, n2, destination]);
+    /// assert_eq!(cost, 4);
+    /// ```
+    fn dijkstra_with_cost<F>(
+        &self,
+        start: Self::NodeReference,
+        target: Self::NodeReference,
+        cost_fn: F,
+    ) -> Option<(Vec<Self::NodeReference>, usize)>
+    where
+        F: Fn(&Self::DataType) -> usize,
+        Self: Sized,
+    {
+        dijkstra_by_priority(self, start, target, cost_fn, |cost, _| cost)
+    }
 
-        reconstruct_path::<Self>(came_from, start, target)
+    /// Same as [`Graph::dijkstra_with_cost`], but when several nodes in the frontier have the same cost, the smallest [`Graph::NodeReference`] is expanded first.
+    /// This way the returned path is always the same, even if there are several shortest paths.
+    ///
+    /// # Arguments
+    ///
+    /// * `start`       - The node where the search starts.
+    /// * `target`      - The target node, where the search will terminate.
+    /// * `cost_fn`     - A function that calculates the cost of traversing given the data stored in a node.
+    ///
+    /// # Example
+    /// ```
+    /// use aoc_helper::graph::{Graph, vec_graph::VecGraph};
+    ///
+    /// let mut graph = VecGraph::new();
+    ///
+    /// let start = graph.add_node(0);
+    /// let a = graph.add_node(1);
+    /// let b = graph.add_node(1);
+    /// let destination = graph.add_node(1);
+    ///
+    /// // Both paths cost 2, `a` wins because it was added first
+    /// graph.add_edge(start, b);
+    /// graph.add_edge(start, a);
+    /// graph.add_edge(b, destination);
+    /// graph.add_edge(a, destination);
+    ///
+    /// let (path, cost) = graph.dijkstra_with_cost_ordered(start, destination, |&d| d).unwrap();
+    ///
+    /// assert_eq!(&path, &[start, a, destination]);
+    /// assert_eq!(cost, 2);
+    /// ```
+    fn dijkstra_with_cost_ordered<F>(
+        &self,
+        start: Self::NodeReference,
+        target: Self::NodeReference,
+        cost_fn: F,
+    ) -> Option<(Vec<Self::NodeReference>, usize)>
+    where
+        F: Fn(&Self::DataType) -> usize,
+        Self: Sized,
+        Self::NodeReference: Ord,
+    {
+        dijkstra_by_priority(self, start, target, cost_fn, |cost, node| (cost, node.clone()))
     }
 
     /// Search the graph for the shortest path between two nodes, using Dijkstra’s Algorithm.
@@ -230,18 +294,18 @@ pub trait Graph: IntoIterator {
         let frontier_indices = self.find_nodes(frontier_fn);
 
         let mut frontier = DoublePriorityQueue::new();
-        frontier_indices.iter().for_each(|&i| {
-            frontier.push(i, 0);
+        frontier_indices.iter().for_each(|i| {
+            frontier.push(i.clone(), 0);
         });
 
         let mut came_from = HashMap::new();
-        frontier_indices.iter().for_each(|&i| {
-            came_from.insert(i, i);
+        frontier_indices.iter().for_each(|i| {
+            came_from.insert(i.clone(), i.clone());
         });
 
         let mut cost_so_far = HashMap::new();
-        frontier_indices.iter().for_each(|&i| {
-            cost_so_far.insert(i, 0);
+        frontier_indices.iter().for_each(|i| {
+            cost_so_far.insert(i.clone(), 0);
         });
 
         let mut target = None;
@@ -256,11 +320,11 @@ pub trait Graph: IntoIterator {
 
             for next in self.get_neighbors(&current) {
                 let data = self.get_data(&next).unwrap();
-                let new_cost = cost_fn(data) + cost_so_far[&current];
+                let new_cost = cost_fn(data).saturating_add(cost_so_far[&current]);
 
                 if !cost_so_far.contains_key(&next) || new_cost < cost_so_far[&next] {
-                    cost_so_far.insert(next, new_cost);
-                    came_from.insert(next, current);
+                    cost_so_far.insert(next.clone(), new_cost);
+                    came_from.insert(next.clone(), current.clone());
                     frontier.push(next, new_cost);
                 }
             }
@@ -270,13 +334,99 @@ pub trait Graph: IntoIterator {
     }
 }
 
-fn reconstruct_path<G>(
-    came_from: HashMap<G::NodeReference, G::NodeReference>,
+/// Run Dijkstra's Algorithm from `start` to `target`, where `priority` turns the cost of reaching a node (and the node itself) into its priority in the frontier.
+fn dijkstra_by_priority<G, F, P, O>(
+    graph: &G,
     start: G::NodeReference,
     target: G::NodeReference,
-) -> Vec<G::NodeReference>
+    cost_fn: F,
+    priority: P,
+) -> Option<(Vec<G::NodeReference>, usize)>
 where
-    G: Graph + Sized,
+    G: Graph,
+    F: Fn(&G::DataType) -> usize,
+    P: Fn(usize, &G::NodeReference) -> O,
+    O: Ord,
+{
+    let mut frontier = DoublePriorityQueue::new();
+    frontier.push(start.clone(), priority(0, &start));
+
+    let mut came_from = HashMap::new();
+    came_from.insert(start.clone(), start.clone());
+
+    let mut cost_so_far = HashMap::new();
+    cost_so_far.insert(start.clone(), 0);
+
+    while !frontier.is_empty() {
+        let (current, _) = frontier.pop_min().unwrap();
+
+        if current == target {
+            break;
+        }
+
+        for next in graph.get_neighbors(&current) {
+            let data = graph.get_data(&next).unwrap();
+            let new_cost = cost_fn(data).saturating_add(cost_so_far[&current]);
+
+            if !cost_so_far.contains_key(&next) || new_cost < cost_so_far[&next] {
+                cost_so_far.insert(next.clone(), new_cost);
+                came_from.insert(next.clone(), current.clone());
+                frontier.push(next.clone(), priority(new_cost, &next));
+            }
+        }
+    }
+
+    let cost = *cost_so_far.get(&target)?;
+
+    Some((reconstruct_path(&came_from, start, target), cost))
+}
+
+/// Reconstruct the path from `start` to `target` using a shortest path tree, as returned by [`VecGraph::shortest_path_tree`](vec_graph::VecGraph::shortest_path_tree).
+/// Returns an empty [`Vec`] if `target` is not part of the tree (i.e. it is not reachable from `start`).
+///
+/// # Arguments
+///
+/// * `tree` - A map from each reached node to its predecessor on the shortest path. The start node maps to itself.
+/// * `start` - The node where the search started.
+/// * `target` - The node where the path should end.
+pub fn reconstruct_from_tree(
+    tree: &HashMap<NodeIndex, NodeIndex>,
+    start: NodeIndex,
+    target: NodeIndex,
+) -> Vec<NodeIndex> {
+    reconstruct_path(tree, start, target)
+}
+
+/// Reconstruct the path from `start` to `target` by following the predecessors stored in `came_from`, starting at `target`.
+/// Returns an empty [`Vec`] if `target` is not in `came_from`.
+///
+/// # Arguments
+///
+/// * `came_from` - A map from each node to the node it was reached from. The start node should map to itself.
+/// * `start` - The node where the path starts.
+/// * `target` - The node where the path ends.
+///
+/// # Example
+///
+/// ```
+/// use std::collections::HashMap;
+/// use aoc_helper::graph::reconstruct_path;
+///
+/// // A search over (row, col) positions, that went (0, 0) -> (0, 1) -> (1, 1)
+/// let mut came_from = HashMap::new();
+/// came_from.insert((0, 0), (0, 0));
+/// came_from.insert((0, 1), (0, 0));
+/// came_from.insert((1, 1), (0, 1));
+/// came_from.insert((1, 0), (0, 0));
+///
+/// let path = reconstruct_path(&came_from, (0, 0), (1, 1));
+/// assert_eq!(path, vec![(0, 0), (0, 1), (1, 1)]);
+///
+/// assert!(reconstruct_path(&came_from, (0, 0), (5, 5)).is_empty());
+/// ```
+pub fn reconstruct_path<N>(came_from: &HashMap<N, N>, start: N, target: N) -> Vec<N>
+where
+    N: Eq + Hash + Clone,
 {
     let mut path = Vec::new();
 
@@ -287,8 +437,9 @@ where
     let mut current = target;
 
     while current != start {
+        let previous = came_from[&current].clone();
         path.push(current);
-        current = came_from[&current]
+        current = previous;
     }
 
     path.push(start);
@@ -314,11 +465,12 @@ where
     let mut current = target.unwrap();
 
     while !start_nodes.contains(&current) {
+        let previous = came_from[&current].clone();
         path.push(current);
-        current = came_from[&current];
+        current = previous;
     }
 
-    path.push(came_from[&current]); // Start node
+    path.push(came_from[&current].clone()); // Start node
     path.reverse();
 
     path
@@ -329,6 +481,23 @@ pub struct NodeIndex(pub usize);
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
 pub struct EdgeIndex(pub usize);
 
+/// Errors that can occur when running an algorithm on a graph.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GraphError {
+    /// The algorithm requires an acyclic graph, but the graph contains a cycle.
+    Cycle,
+}
+
+impl Display for GraphError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GraphError::Cycle => write!(f, "The graph contains a cycle"),
+        }
+    }
+}
+
+impl Error for GraphError {}
+
 pub struct GraphIterator<'a, T>
 where
     T: Graph + Sized,
@@ -345,5 +514,112 @@ where
 }
 
 pub mod grid;
+pub mod grid_util;
+pub mod node_bit_set;
 pub mod rc_graph;
 pub mod vec_graph;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// A minimal [`Graph`] that uses the name of the node as its [`Graph::NodeReference`].
+    struct NamedGraph {
+        nodes: HashMap<String, (usize, Vec<String>)>,
+    }
+
+    impl IntoIterator for NamedGraph {
+        type Item = String;
+        type IntoIter = std::collections::hash_map::IntoKeys<String, (usize, Vec<String>)>;
+
+        fn into_iter(self) -> Self::IntoIter {
+            self.nodes.into_keys()
+        }
+    }
+
+    impl Graph for NamedGraph {
+        type DataType = usize;
+        type NodeReference = String;
+        type EdgeReference = ();
+
+        fn new() -> Self {
+            NamedGraph {
+                nodes: HashMap::new(),
+            }
+        }
+
+        fn add_node(&mut self, data: Self::DataType) -> Self::NodeReference {
+            let name = format!("node_{}", self.nodes.len());
+            self.nodes.insert(name.clone(), (data, Vec::new()));
+            name
+        }
+
+        fn add_edge(&mut self, source: Self::NodeReference, target: Self::NodeReference) {
+            self.nodes.get_mut(&source).unwrap().1.push(target);
+        }
+
+        fn get_data(&self, node: &Self::NodeReference) -> Option<&Self::DataType> {
+            self.nodes.get(node).map(|(data, _)| data)
+        }
+
+        fn get_data_mut(&mut self, node: &Self::NodeReference) -> Option<&mut Self::DataType> {
+            self.nodes.get_mut(node).map(|(data, _)| data)
+        }
+
+        fn find<F>(&self, predicate: F) -> Option<Self::NodeReference>
+        where
+            F: Fn(&Self::DataType) -> bool,
+        {
+            self.find_nodes(predicate).into_iter().next()
+        }
+
+        fn find_nodes<F>(&self, predicate: F) -> Vec<Self::NodeReference>
+        where
+            F: Fn(&Self::DataType) -> bool,
+        {
+            let mut nodes = self
+                .nodes
+                .iter()
+                .filter(|(_, (data, _))| predicate(data))
+                .map(|(name, _)| name.clone())
+                .collect::<Vec<_>>();
+            nodes.sort();
+            nodes
+        }
+
+        fn get_neighbors(&self, node: &Self::NodeReference) -> Vec<Self::NodeReference> {
+            self.nodes[node].1.clone()
+        }
+
+        fn to_dot_file<N, S>(&self, _: N, _: S) -> String
+        where
+            N: Fn(&Self::DataType) -> String,
+            S: Fn(&Self::DataType) -> String,
+        {
+            String::new()
+        }
+    }
+
+    #[test]
+    fn dijkstra_works_with_string_node_reference() {
+        let mut graph = NamedGraph::new();
+
+        let start = graph.add_node(0);
+        let n1 = graph.add_node(1000);
+        let n2 = graph.add_node(1);
+        let n3 = graph.add_node(2);
+        let destination = graph.add_node(3);
+
+        graph.add_edge(start.clone(), n1.clone());
+        graph.add_edge(start.clone(), n2.clone());
+        graph.add_edge(n2.clone(), n3.clone());
+        graph.add_edge(n1.clone(), n3.clone());
+        graph.add_edge(n3.clone(), destination.clone());
+
+        let path = graph.dijkstra(start.clone(), destination.clone(), |&d| d);
+        assert_eq!(&path, &[start.clone(), n2.clone(), n3.clone(), destination.clone()]);
+
+        let path = graph.dijkstra_search_with_closure(|&d| d == 0, |&d| d == 3, |&d| d);
+        assert_eq!(&path, &[start, n2, n3, destination]);
+    }
+}
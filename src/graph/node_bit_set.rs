@@ -0,0 +1,159 @@
+use super::NodeIndex;
+
+const BITS: usize = u64::BITS as usize;
+
+/// A set of [`NodeIndex`] values, stored as one bit per node.
+/// Inserting, removing and checking a node are a single bit operation, which makes this much faster than a [`HashSet`](std::collections::HashSet) for the visited set of a search that runs in a hot loop (e.g. enumerating paths with a depth-first search).
+/// The set takes one bit for every possible index, so it is best suited to graphs where the node indices are dense, like [`VecGraph`](super::vec_graph::VecGraph).
+///
+/// # Example:
+///
+/// ```
+/// use aoc_helper::graph::{NodeIndex, node_bit_set::NodeBitSet};
+///
+/// let mut visited = NodeBitSet::new(10);
+///
+/// assert!(visited.insert(NodeIndex(3)));
+/// assert!(!visited.insert(NodeIndex(3)));
+/// assert!(visited.contains(NodeIndex(3)));
+///
+/// assert!(visited.remove(NodeIndex(3)));
+/// assert!(visited.is_empty());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NodeBitSet {
+    words: Vec<u64>,
+    len: usize,
+}
+
+impl NodeBitSet {
+    /// Creates a new, empty [`NodeBitSet`] with room for the nodes with an index less than `size`, e.g. [`VecGraph::node_count`](super::vec_graph::VecGraph::node_count).
+    /// Inserting a larger index is allowed, and grows the set.
+    pub fn new(size: usize) -> NodeBitSet {
+        NodeBitSet {
+            words: vec![0; size.div_ceil(BITS)],
+            len: 0,
+        }
+    }
+
+    /// Add `node` to the set. Returns `true` if the node was not in the set before.
+    pub fn insert(&mut self, node: NodeIndex) -> bool {
+        let (word, mask) = Self::position(node);
+
+        if word >= self.words.len() {
+            self.words.resize(word + 1, 0);
+        }
+
+        let inserted = self.words[word] & mask == 0;
+        if inserted {
+            self.words[word] |= mask;
+            self.len += 1;
+        }
+
+        inserted
+    }
+
+    /// Remove `node` from the set. Returns `true` if the node was in the set.
+    pub fn remove(&mut self, node: NodeIndex) -> bool {
+        let (word, mask) = Self::position(node);
+
+        let removed = self.words.get(word).is_some_and(|w| w & mask != 0);
+        if removed {
+            self.words[word] &= !mask;
+            self.len -= 1;
+        }
+
+        removed
+    }
+
+    /// Check if `node` is in the set.
+    pub fn contains(&self, node: NodeIndex) -> bool {
+        let (word, mask) = Self::position(node);
+
+        self.words.get(word).is_some_and(|w| w & mask != 0)
+    }
+
+    /// Return the number of nodes in the set.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Return `true` if the set contains no nodes.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Remove every node from the set, keeping the allocated memory.
+    pub fn clear(&mut self) {
+        self.words.fill(0);
+        self.len = 0;
+    }
+
+    /// Return the index of the word that holds the bit of `node`, and a mask that selects the bit.
+    fn position(node: NodeIndex) -> (usize, u64) {
+        (node.0 / BITS, 1 << (node.0 % BITS))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashSet;
+
+    use super::*;
+
+    #[test]
+    fn insert_contains_remove_work() {
+        let mut set = NodeBitSet::new(100);
+
+        for i in [0, 1, 63, 64, 99] {
+            assert!(set.insert(NodeIndex(i)));
+        }
+        assert!(!set.insert(NodeIndex(64)));
+        assert_eq!(set.len(), 5);
+
+        assert!(set.contains(NodeIndex(63)));
+        assert!(set.contains(NodeIndex(64)));
+        assert!(!set.contains(NodeIndex(65)));
+        assert!(!set.contains(NodeIndex(1000)));
+
+        assert!(set.remove(NodeIndex(63)));
+        assert!(!set.remove(NodeIndex(63)));
+        assert!(!set.remove(NodeIndex(1000)));
+        assert!(!set.contains(NodeIndex(63)));
+        assert_eq!(set.len(), 4);
+
+        set.clear();
+        assert!(set.is_empty());
+        assert!(!set.contains(NodeIndex(0)));
+    }
+
+    #[test]
+    fn insert_grows_the_set() {
+        let mut set = NodeBitSet::new(0);
+
+        assert!(set.insert(NodeIndex(200)));
+        assert!(set.contains(NodeIndex(200)));
+        assert!(!set.contains(NodeIndex(199)));
+        assert_eq!(set.len(), 1);
+    }
+
+    #[test]
+    fn matches_hash_set() {
+        let mut bits = NodeBitSet::new(50);
+        let mut hashed = HashSet::new();
+
+        // A simple deterministic sequence of inserts and removes
+        for i in 0..500_usize {
+            let node = NodeIndex(i * 37 % 130);
+
+            if i % 3 == 0 {
+                assert_eq!(bits.remove(node), hashed.remove(&node));
+            } else {
+                assert_eq!(bits.insert(node), hashed.insert(node));
+            }
+        }
+
+        assert_eq!(bits.len(), hashed.len());
+        assert!((0..130).all(|i| bits.contains(NodeIndex(i)) == hashed.contains(&NodeIndex(i))));
+    }
+}
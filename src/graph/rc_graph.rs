@@ -175,12 +175,40 @@ impl<T: Clone> Graph for RcGraph<T> {
 }
 
 impl<T: Clone> RcGraph<T> {
-    pub fn iter(&self) -> GraphIterator<RcGraph<T>> {
+    pub fn iter(&self) -> GraphIterator<'_, RcGraph<T>> {
         GraphIterator {
             graph: self,
             index: 0,
         }
     }
+
+    /// Iterate over the nodes in this graph, together with the data stored in them.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use aoc_helper::graph::{Graph, rc_graph::RcGraph};
+    ///
+    /// let mut graph = RcGraph::new();
+    ///
+    /// let n0 = graph.add_node('a');
+    /// let n1 = graph.add_node('b');
+    ///
+    /// assert_eq!(graph.iter_data().collect::<Vec<_>>(), vec![(n0, &'a'), (n1, &'b')]);
+    /// ```
+    pub fn iter_data(&self) -> impl Iterator<Item = (NodeIndex, &T)> {
+        self.nodes.iter().map(|node| (node.index, &node.data))
+    }
+
+    /// Returns the number of nodes in this graph.
+    pub fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Returns `true` if `node` references a node in this graph.
+    pub fn contains_node(&self, node: NodeIndex) -> bool {
+        node.0 < self.nodes.len()
+    }
 }
 
 impl<'a, T: Clone> Iterator for GraphIterator<'a, RcGraph<T>> {
@@ -238,7 +266,7 @@ pub mod test {
     fn can_create_new_rc_graph() {
         let graph: RcGraph<usize> = RcGraph::new();
 
-        assert!(graph.nodes.is_empty());
+        assert_eq!(graph.node_count(), 0);
     }
 
     #[test]
@@ -250,9 +278,51 @@ pub mod test {
 
         assert_eq!(n1.0, 0);
         assert_eq!(n2.0, 1);
-        assert_eq!(graph.nodes.len(), 2);
-        assert_eq!(graph.nodes[0].data, 0);
-        assert_eq!(graph.nodes[1].data, 1);
+        assert_eq!(graph.node_count(), 2);
+        assert_eq!(graph.iter_data().collect_vec(), vec![(n1, &0), (n2, &1)]);
+    }
+
+    #[test]
+    fn node_count_works() {
+        let mut graph = RcGraph::new();
+        assert_eq!(graph.node_count(), 0);
+
+        for i in 0..10 {
+            graph.add_node(i);
+        }
+
+        assert_eq!(graph.node_count(), 10);
+    }
+
+    #[test]
+    fn contains_node_works() {
+        let mut graph = RcGraph::new();
+        assert!(!graph.contains_node(NodeIndex(0)));
+
+        let n0 = graph.add_node(0);
+        let n1 = graph.add_node(1);
+
+        assert!(graph.contains_node(n0));
+        assert!(graph.contains_node(n1));
+        assert!(!graph.contains_node(NodeIndex(2)));
+        assert!(!graph.contains_node(NodeIndex(usize::MAX)));
+    }
+
+    #[test]
+    fn iter_data_matches_iter() {
+        let mut graph = RcGraph::new();
+
+        for c in ['a', 'b', 'c'] {
+            graph.add_node(c);
+        }
+
+        let data = graph.iter_data().collect_vec();
+        let expected = graph
+            .iter()
+            .map(|n| (*n, graph.get_data(n).unwrap()))
+            .collect_vec();
+
+        assert_eq!(data, expected);
     }
 
     #[test]
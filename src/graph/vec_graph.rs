@@ -1,298 +1,3016 @@
 #![allow(dead_code)]
+use std::{
+    cmp::{Ordering, Reverse},
+    collections::{HashMap, HashSet, VecDeque},
+    ops::Add,
+};
+
+use num_traits::Zero;
+use priority_queue::DoublePriorityQueue;
+
 use crate::{
     direction::relative_direction::RelativeDirection, geometry::point::Point2D, iter_ext::IterExt,
 };
 
-use super::{EdgeIndex, Graph, GraphIntoIterator, GraphIterator, NodeIndex};
+use super::{
+    node_bit_set::NodeBitSet, reconstruct_path, EdgeIndex, Graph, GraphError, GraphIntoIterator,
+    GraphIterator, NodeIndex,
+};
 
 // An implementation of a graph datastructure, using vectors to store nodes and edges.
 // Based on: https://smallcultfollowing.com/babysteps/blog/2015/04/06/modeling-graphs-in-rust-using-vector-indices/
-pub struct VecGraph<T> {
+// Each edge stores a weight of type E. Unweighted graphs use the default `()` weight, and implement the [`Graph`] trait.
+#[derive(Clone)]
+pub struct VecGraph<T, E = ()> {
     nodes: Vec<NodeData<T>>,
-    edges: Vec<EdgeData>,
+    edges: Vec<EdgeData<E>>,
+}
+
+impl<T, E> Default for VecGraph<T, E> {
+    fn default() -> Self {
+        VecGraph {
+            nodes: Vec::new(),
+            edges: Vec::new(),
+        }
+    }
+}
+
+/// Two [`VecGraph`]s are equal if they contain the same node data in the same index order, and each node has the same set of outgoing edges, with the same weights.
+/// The order in which the edges were added does not matter.
+/// Tombstoned (removed) node slots compare as empty: they are only equal to another tombstoned slot.
+impl<T: PartialEq, E: PartialEq> PartialEq for VecGraph<T, E> {
+    fn eq(&self, other: &Self) -> bool {
+        if self.nodes.len() != other.nodes.len() {
+            return false;
+        }
+
+        self.nodes.iter().zip(other.nodes.iter()).all(|(a, b)| {
+            if a.data != b.data {
+                return false;
+            }
+
+            // Match up the outgoing edges one by one, since the weights are not necessarily ordered
+            let mut b_edges = other.weighted_successors(b.index).collect_vec();
+            if self.weighted_successors(a.index).count() != b_edges.len() {
+                return false;
+            }
+
+            self.weighted_successors(a.index).all(|edge| {
+                match b_edges.iter().position(|e| *e == edge) {
+                    Some(position) => {
+                        b_edges.swap_remove(position);
+                        true
+                    }
+                    None => false,
+                }
+            })
+        })
+    }
+}
+
+impl<T> Graph for VecGraph<T> {
+    type DataType = T;
+    type NodeReference = NodeIndex;
+    type EdgeReference = EdgeIndex;
+
+    fn new() -> Self
+    where
+        Self: Sized,
+    {
+        VecGraph::default()
+    }
+
+    fn add_node(&mut self, data: Self::DataType) -> Self::NodeReference {
+        self.push_node(data)
+    }
+
+    fn add_edge(&mut self, source: Self::NodeReference, target: Self::NodeReference) {
+        self.add_weighted_edge(source, target, ());
+    }
+
+    fn get_data(&self, node: &Self::NodeReference) -> Option<&Self::DataType> {
+        self.node_data(node)
+    }
+
+    fn get_data_mut(&mut self, node: &Self::NodeReference) -> Option<&mut Self::DataType> {
+        self.node_data_mut(node)
+    }
+
+    fn get_neighbors(&self, node: &Self::NodeReference) -> Vec<Self::NodeReference> {
+        self.successors(*node).collect_vec()
+    }
+
+    fn find<F>(&self, predicate: F) -> Option<Self::NodeReference>
+    where
+        F: Fn(&Self::DataType) -> bool,
+    {
+        for node in &self.nodes {
+            if let Some(data) = &node.data {
+                if predicate(data) {
+                    return Some(node.index);
+                }
+            }
+        }
+
+        None
+    }
+
+    fn find_nodes<F>(&self, predicate: F) -> Vec<Self::NodeReference>
+    where
+        F: Fn(&Self::DataType) -> bool,
+    {
+        self.nodes
+            .iter()
+            .filter(|node| node.data.as_ref().is_some_and(&predicate))
+            .map(|node| node.index)
+            .collect()
+    }
+
+    fn to_dot_file<N, S>(&self, node_name_fn: N, node_style_fn: S) -> String
+    where
+        N: Fn(&Self::DataType) -> String,
+        S: Fn(&Self::DataType) -> String,
+    {
+        let mut graphviz = String::from("digraph {\n");
+
+        self.live_nodes().for_each(|(_, data)| {
+            let name = node_name_fn(data);
+            let style = node_style_fn(data);
+            graphviz.push_str(format!(" {} [{}]\n", name, style).as_str());
+        });
+
+        graphviz.push('\n');
+
+        #[allow(clippy::redundant_closure)]
+        self.live_nodes().for_each(|(node, data)| {
+            let neighbors = self
+                .get_neighbors(&node.index)
+                .iter()
+                .map(|n| self.get_data(n).unwrap())
+                .map(|d| node_name_fn(d))
+                .collect_vec().join(" ");
+
+            let name = node_name_fn(data);
+
+            graphviz.push_str(format!(" {} -> {{ {} }}\n", name, neighbors).as_str());
+
+        });
+
+        graphviz.push('}');
+        graphviz
+    }
+}
+
+impl<'a, T> Iterator for GraphIterator<'a, VecGraph<T>> {
+    type Item = &'a <VecGraph<T> as Graph>::NodeReference;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // Skip over removed nodes
+        while self.index < self.graph.nodes.len() {
+            self.index += 1;
+
+            let node = &self.graph.nodes[self.index - 1];
+            if node.data.is_some() {
+                return Some(&node.index);
+            }
+        }
+
+        None
+    }
+}
+
+impl<T> Iterator for GraphIntoIterator<VecGraph<T>> {
+    type Item = <VecGraph<T> as Graph>::NodeReference;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // Skip over removed nodes
+        while !self.graph.nodes.is_empty() {
+            let node = self.graph.nodes.remove(0);
+            if node.data.is_some() {
+                return Some(node.index);
+            }
+        }
+
+        None
+    }
+}
+
+impl<T> IntoIterator for VecGraph<T> {
+    type Item = <Self as Graph>::NodeReference;
+
+    type IntoIter = GraphIntoIterator<VecGraph<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        GraphIntoIterator { graph: self }
+    }
 }
 
-impl<T> Default for VecGraph<T> {
-    fn default() -> Self {
-        Self::new()
+impl<T, E> VecGraph<T, E> {
+    /// Add a node containing `data` to the graph, and return its [`NodeIndex`].
+    /// This is the same as [`Graph::add_node`], but it is also available for weighted graphs.
+    pub fn push_node(&mut self, data: T) -> NodeIndex {
+        let index = self.nodes.len();
+        self.nodes.push(NodeData {
+            data: Some(data),
+            index: NodeIndex(index),
+            first_outgoing_edge: None,
+            out_degree: 0,
+        });
+        NodeIndex(index)
+    }
+
+    /// Add a directed edge from `source` to `target` that carries `weight`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either `source` or `target` references a node that does not exist, or that has been removed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use aoc_helper::graph::vec_graph::VecGraph;
+    ///
+    /// let mut graph: VecGraph<char, usize> = VecGraph::default();
+    ///
+    /// let a = graph.push_node('a');
+    /// let b = graph.push_node('b');
+    ///
+    /// graph.add_weighted_edge(a, b, 5);
+    ///
+    /// assert_eq!(graph.weighted_successors(a).collect::<Vec<_>>(), vec![(b, &5)]);
+    /// ```
+    pub fn add_weighted_edge(&mut self, source: NodeIndex, target: NodeIndex, weight: E) {
+        let edge_index = self.edges.len();
+
+        // TODO: should we return something (E.g. Result) instead of panicking?
+        if !self.contains_node(target) {
+            panic!("Target node not found!");
+        }
+
+        let Some(source_node) = self.nodes.get_mut(source.0).filter(|n| n.data.is_some()) else {
+            panic!("Source node not found.");
+        };
+
+        self.edges.push(EdgeData {
+            target,
+            next_outgoing_edge: source_node.first_outgoing_edge,
+            weight,
+        });
+
+        source_node.first_outgoing_edge = Some(EdgeIndex(edge_index));
+        source_node.out_degree += 1;
+    }
+
+    /// Return a reference to the data stored in `node`, or [`None`] if it does not exist, or has been removed.
+    /// This is the same as [`Graph::get_data`], but it is also available for weighted graphs.
+    pub fn node_data(&self, node: &NodeIndex) -> Option<&T> {
+        if let Some(node_data) = self.nodes.get(node.0) {
+            node_data.data.as_ref()
+        } else {
+            None
+        }
+    }
+
+    /// Return a mutable reference to the data stored in `node`, or [`None`] if it does not exist, or has been removed.
+    /// This is the same as [`Graph::get_data_mut`], but it is also available for weighted graphs.
+    pub fn node_data_mut(&mut self, node: &NodeIndex) -> Option<&mut T> {
+        if let Some(node_data) = self.nodes.get_mut(node.0) {
+            node_data.data.as_mut()
+        } else {
+            None
+        }
+    }
+
+    /// Iterate over the nodes in this graph, together with the data stored in them, in the order the nodes were added. Removed nodes are skipped.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use aoc_helper::graph::{Graph, vec_graph::VecGraph};
+    /// let mut graph = VecGraph::new();
+    ///
+    /// let n0 = graph.add_node('a');
+    /// let n1 = graph.add_node('b');
+    ///
+    /// assert_eq!(graph.iter_data().collect::<Vec<_>>(), vec![(n0, &'a'), (n1, &'b')]);
+    /// ```
+    pub fn iter_data(&self) -> impl Iterator<Item = (NodeIndex, &T)> {
+        self.nodes
+            .iter()
+            .filter_map(|node| node.data.as_ref().map(|data| (node.index, data)))
+    }
+
+    /// Return a [`Successors`] that can be used to iterate over the nodes that are connected to 'source'.
+    /// The number of successors is known up front, so [`Successors`] implements [`ExactSizeIterator`].
+    ///
+    /// # Arguments
+    ///  * 'source' - The source node.
+    ///
+    /// # Panics
+    ///
+    /// Panics if 'source' contains an index that does not correspond to an existing node.
+    pub fn successors(&self, source: NodeIndex) -> Successors<'_, T, E> {
+        if let Some(n) = self.nodes.get(source.0) {
+            Successors {
+                graph: self,
+                current_edge_index: n.first_outgoing_edge,
+                remaining: n.out_degree,
+            }
+        } else {
+            panic!("Source not found!");
+        }
+    }
+
+    /// Iterate over the outgoing edges of 'source', yielding the [`EdgeIndex`] of each edge together with the node it points to.
+    /// Edges are yielded in the same order as [`successors`](VecGraph::successors) yields their targets, i.e. the most recently added edge first.
+    ///
+    /// # Arguments
+    ///  * 'source' - The source node.
+    ///
+    /// # Panics
+    ///
+    /// Panics if 'source' contains an index that does not correspond to an existing node.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use aoc_helper::graph::{EdgeIndex, Graph, vec_graph::VecGraph};
+    /// let mut graph = VecGraph::new();
+    ///
+    /// let n0 = graph.add_node(0);
+    /// let n1 = graph.add_node(1);
+    ///
+    /// graph.add_edge(n0, n1);
+    ///
+    /// assert_eq!(graph.successor_edges(n0).collect::<Vec<_>>(), vec![(EdgeIndex(0), n1)]);
+    /// ```
+    pub fn successor_edges(&self, source: NodeIndex) -> impl Iterator<Item = (EdgeIndex, NodeIndex)> + '_ {
+        let mut successors = self.successors(source);
+
+        std::iter::from_fn(move || successors.next_edge())
+    }
+
+    /// Return references to the data of the nodes that 'node' has an edge to, in the same order as [`successors`](VecGraph::successors).
+    ///
+    /// # Panics
+    ///
+    /// Panics if 'node' contains an index that does not correspond to an existing node.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use aoc_helper::graph::{Graph, vec_graph::VecGraph};
+    /// let mut graph = VecGraph::new();
+    ///
+    /// let n0 = graph.add_node('a');
+    /// let n1 = graph.add_node('b');
+    ///
+    /// graph.add_edge(n0, n1);
+    ///
+    /// assert_eq!(graph.neighbor_data(n0), vec![&'b']);
+    /// ```
+    pub fn neighbor_data(&self, node: NodeIndex) -> Vec<&T> {
+        self.successors(node)
+            .map(|next| self.node_data(&next).unwrap())
+            .collect()
+    }
+
+    /// Return the nodes that 'node' has an edge to, in the given [`NeighborOrder`].
+    /// [`successors`](VecGraph::successors) (and [`Graph::get_neighbors`]) always yield the most recently added edge first, because each new edge is put at the front of the outgoing edge list of its source. Use this method when a different order is needed.
+    ///
+    /// # Arguments
+    ///  * 'node' - The source node.
+    ///  * 'order' - The order of the returned nodes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if 'node' contains an index that does not correspond to an existing node.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use aoc_helper::graph::{Graph, vec_graph::{NeighborOrder, VecGraph}};
+    /// let mut graph = VecGraph::new();
+    ///
+    /// let n0 = graph.add_node(0);
+    /// let n1 = graph.add_node(1);
+    /// let n2 = graph.add_node(2);
+    ///
+    /// graph.add_edge(n0, n1);
+    /// graph.add_edge(n0, n2);
+    ///
+    /// assert_eq!(graph.neighbors_ordered(n0, NeighborOrder::ReverseInsertion), vec![n2, n1]);
+    /// assert_eq!(graph.neighbors_ordered(n0, NeighborOrder::InsertionOrder), vec![n1, n2]);
+    /// ```
+    pub fn neighbors_ordered(&self, node: NodeIndex, order: NeighborOrder) -> Vec<NodeIndex> {
+        let mut neighbors = self.successors(node).collect_vec();
+
+        match order {
+            NeighborOrder::ReverseInsertion => {}
+            NeighborOrder::InsertionOrder => neighbors.reverse(),
+            NeighborOrder::ByIndex => neighbors.sort(),
+        }
+
+        neighbors
+    }
+
+    /// Iterate over the outgoing edges of 'source', yielding the node each edge points to together with the weight of the edge.
+    /// Edges are yielded in the same order as [`successors`](VecGraph::successors) yields their targets.
+    ///
+    /// # Arguments
+    ///  * 'source' - The source node.
+    ///
+    /// # Panics
+    ///
+    /// Panics if 'source' contains an index that does not correspond to an existing node.
+    pub fn weighted_successors(&self, source: NodeIndex) -> impl Iterator<Item = (NodeIndex, &E)> {
+        self.successor_edges(source)
+            .map(|(edge_index, target)| (target, &self.edges[edge_index.0].weight))
+    }
+
+    /// Search the graph for the path between `start` and `target` with the smallest total edge weight, using Dijkstra's Algorithm.
+    /// Returns the path (including both `start` and `target`) together with its total weight, or [`None`] if `target` cannot be reached.
+    /// Unlike [`Graph::dijkstra_with_cost`], the cost is taken from the weights of the edges instead of the data of the nodes. The weights must not be negative.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - The node where the search starts.
+    /// * `target` - The node where the search ends.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use aoc_helper::graph::vec_graph::VecGraph;
+    ///
+    /// let mut graph: VecGraph<char, u32> = VecGraph::default();
+    ///
+    /// let a = graph.push_node('a');
+    /// let b = graph.push_node('b');
+    /// let c = graph.push_node('c');
+    ///
+    /// graph.add_weighted_edge(a, c, 10);
+    /// graph.add_weighted_edge(a, b, 3);
+    /// graph.add_weighted_edge(b, c, 4);
+    ///
+    /// assert_eq!(graph.dijkstra_weighted(a, c), Some((vec![a, b, c], 7)));
+    /// ```
+    pub fn dijkstra_weighted(&self, start: NodeIndex, target: NodeIndex) -> Option<(Vec<NodeIndex>, E)>
+    where
+        E: Zero + Ord + Copy,
+    {
+        let mut frontier = DoublePriorityQueue::new();
+        frontier.push(start, (E::zero(), start));
+
+        let mut came_from = HashMap::new();
+        came_from.insert(start, start);
+
+        let mut cost_so_far = HashMap::new();
+        cost_so_far.insert(start, E::zero());
+
+        while let Some((current, _)) = frontier.pop_min() {
+            if current == target {
+                break;
+            }
+
+            for (next, &weight) in self.weighted_successors(current) {
+                let new_cost = cost_so_far[&current] + weight;
+
+                if !cost_so_far.contains_key(&next) || new_cost < cost_so_far[&next] {
+                    cost_so_far.insert(next, new_cost);
+                    came_from.insert(next, current);
+                    frontier.push(next, (new_cost, next));
+                }
+            }
+        }
+
+        let cost = *cost_so_far.get(&target)?;
+
+        Some((reconstruct_path(&came_from, start, target), cost))
+    }
+
+    /// Exchange the data stored in nodes `a` and `b`. The edges of the nodes are not changed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either `a` or `b` references a node that does not exist, or that has been removed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use aoc_helper::graph::{Graph, vec_graph::VecGraph};
+    ///
+    /// let mut graph = VecGraph::new();
+    ///
+    /// let a = graph.add_node('a');
+    /// let b = graph.add_node('b');
+    ///
+    /// graph.swap(a, b);
+    ///
+    /// assert_eq!(graph.get_data(&a), Some(&'b'));
+    /// assert_eq!(graph.get_data(&b), Some(&'a'));
+    /// ```
+    pub fn swap(&mut self, a: NodeIndex, b: NodeIndex) {
+        assert!(
+            self.contains_node(a) && self.contains_node(b),
+            "Node not found!"
+        );
+
+        if a == b {
+            return;
+        }
+
+        let a_data = self.nodes[a.0].data.take();
+        self.nodes[a.0].data = std::mem::replace(&mut self.nodes[b.0].data, a_data);
+    }
+
+    /// Returns a [`GraphIterator<VecGraph<T>>`] which can be used to iterate over the node references in this graph.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use aoc_helper::graph::{Graph, vec_graph::VecGraph};
+    /// let mut graph: VecGraph<usize> = VecGraph::new();
+    ///
+    /// graph.add_node(1);
+    /// graph.add_node(2);
+    /// graph.add_node(3);
+    /// graph.add_node(4);
+    ///
+    /// let mut graph_data = Vec::new();
+    /// for node in graph.iter() {
+    ///     graph_data.push(*graph.get_data(node).unwrap());
+    /// }
+    ///
+    /// assert_eq!(graph_data.len(), 4);
+    /// assert_eq!(&graph_data, &[1, 2, 3, 4]);
+    /// ```
+    pub fn iter(&self) -> GraphIterator<'_, VecGraph<T, E>>
+    where
+        Self: Graph,
+    {
+        GraphIterator {
+            graph: self,
+            index: 0,
+        }
+    }
+
+    /// Returns the number of nodes in this graph. Removed nodes are not counted.
+    pub fn node_count(&self) -> usize {
+        self.nodes.iter().filter(|n| n.data.is_some()).count()
+    }
+
+    /// Returns `true` if `node` references a node in this graph that has not been removed.
+    pub fn contains_node(&self, node: NodeIndex) -> bool {
+        self.nodes.get(node.0).is_some_and(|n| n.data.is_some())
+    }
+
+    /// Remove `node` from the graph, together with all of its incoming and outgoing edges, and return the data it contained.
+    /// Returns [`None`] if `node` does not exist, or was already removed.
+    ///
+    /// The node is replaced with a tombstone, so the [`NodeIndex`] of every other node stays valid.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use aoc_helper::graph::{Graph, vec_graph::VecGraph};
+    /// let mut graph = VecGraph::new();
+    ///
+    /// let n0 = graph.add_node(0);
+    /// let n1 = graph.add_node(1);
+    /// let n2 = graph.add_node(2);
+    ///
+    /// graph.add_edge(n0, n1);
+    /// graph.add_edge(n0, n2);
+    ///
+    /// assert_eq!(graph.remove_node(n1), Some(1));
+    /// assert_eq!(&graph.get_neighbors(&n0), &[n2]);
+    /// assert_eq!(graph.get_data(&n2), Some(&2));
+    /// assert_eq!(graph.node_count(), 2);
+    /// ```
+    pub fn remove_node(&mut self, node: NodeIndex) -> Option<T> {
+        let removed = self.nodes.get_mut(node.0)?.data.take()?;
+        self.unlink_removed_nodes();
+
+        Some(removed)
+    }
+
+    /// Remove every node whose data does not satisfy `predicate`, together with all of their incoming and outgoing edges.
+    /// Removed nodes are replaced with tombstones, so the [`NodeIndex`] of every surviving node stays valid.
+    ///
+    /// # Arguments
+    ///
+    /// * `predicate` - A closure that is applied to the data of each node. Nodes for which it returns `false` are removed.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use aoc_helper::graph::{Graph, vec_graph::VecGraph};
+    /// let mut graph = VecGraph::new();
+    ///
+    /// let floor = graph.add_node('.');
+    /// let wall = graph.add_node('#');
+    ///
+    /// graph.add_edge(floor, wall);
+    /// graph.retain_nodes(|&c| c != '#');
+    ///
+    /// assert!(graph.get_neighbors(&floor).is_empty());
+    /// assert!(graph.get_data(&wall).is_none());
+    /// ```
+    pub fn retain_nodes<P>(&mut self, predicate: P)
+    where
+        P: Fn(&T) -> bool,
+    {
+        for node in self.nodes.iter_mut() {
+            if node.data.as_ref().is_some_and(|d| !predicate(d)) {
+                node.data = None;
+            }
+        }
+
+        self.unlink_removed_nodes();
+    }
+
+    /// Find the longest path from `start` to `target` that does not visit any node more than once, and return its total weight.
+    /// The weight of a path is the sum of `edge_weight_fn` over its edges. Returns [`None`] if `target` cannot be reached from `start`.
+    ///
+    /// Every simple path is enumerated with a depth-first search, so this is only feasible on small graphs, e.g. a maze that has been compressed into its junctions with [`contract_edge`](VecGraph::contract_edge) (AoC 2023 Day 23).
+    /// The problem is NP-hard in general, and the running time grows exponentially with the number of nodes.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - The node where the path starts.
+    /// * `target` - The node where the path ends.
+    /// * `edge_weight_fn` - A function that calculates the length of an edge from its weight.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use aoc_helper::graph::vec_graph::VecGraph;
+    ///
+    /// let mut graph: VecGraph<char, usize> = VecGraph::default();
+    ///
+    /// let a = graph.push_node('a');
+    /// let b = graph.push_node('b');
+    /// let c = graph.push_node('c');
+    ///
+    /// graph.add_weighted_edge(a, c, 10);
+    /// graph.add_weighted_edge(a, b, 6);
+    /// graph.add_weighted_edge(b, c, 6);
+    ///
+    /// assert_eq!(graph.longest_simple_path(a, c, |&w| w), Some(12));
+    /// assert_eq!(graph.longest_simple_path(c, a, |&w| w), None);
+    /// ```
+    pub fn longest_simple_path<F>(&self, start: NodeIndex, target: NodeIndex, edge_weight_fn: F) -> Option<usize>
+    where
+        F: Fn(&E) -> usize,
+    {
+        if !self.contains_node(start) {
+            return None;
+        }
+
+        let mut visited = NodeBitSet::new(self.nodes.len());
+        visited.insert(start);
+
+        self.longest_simple_path_from(start, target, &edge_weight_fn, &mut visited)
+    }
+
+    /// Merge node `b` into node `a`, by replacing every path that goes through the edge between them with a single edge.
+    /// Every edge `b -> c` becomes an edge `a -> c`, whose weight is the sum of the weights of `a -> b` and `b -> c`.
+    /// If there is also an edge `b -> a`, every edge `c -> b` becomes an edge `c -> a` in the same way. Otherwise, the edges going into `b` are dropped, as they can not reach `a`.
+    /// `b` is removed afterwards. Edges that `a` already had are kept, so contracting can create parallel edges.
+    /// This is how the corridors of a maze can be compressed into weighted edges between junctions (e.g. AoC 2023 Day 23).
+    /// Each call has to find the edges going into `b` by looking at the whole graph, so use [`contract_edges`](VecGraph::contract_edges) to contract many edges at once.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no edge from `a` to `b`, or if `a` and `b` are the same node.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use aoc_helper::graph::vec_graph::VecGraph;
+    ///
+    /// let mut graph: VecGraph<char, u32> = VecGraph::default();
+    ///
+    /// let a = graph.push_node('a');
+    /// let b = graph.push_node('b');
+    /// let c = graph.push_node('c');
+    ///
+    /// graph.add_weighted_edge(a, b, 2);
+    /// graph.add_weighted_edge(b, c, 3);
+    ///
+    /// graph.contract_edge(a, b);
+    ///
+    /// assert_eq!(graph.weighted_successors(a).collect::<Vec<_>>(), vec![(c, &5)]);
+    /// assert!(graph.node_data(&b).is_none());
+    /// ```
+    pub fn contract_edge(&mut self, a: NodeIndex, b: NodeIndex)
+    where
+        E: Add<Output = E> + Copy,
+    {
+        self.contract_edges([(a, b)]);
+    }
+
+    /// Contract every (`a`, `b`) pair of `edges`, in order, as if [`contract_edge`](VecGraph::contract_edge) was called for each of them.
+    /// The edges going into each node are only collected once, so this takes linear time in the size of the graph and the number of contracted edges, instead of quadratic time.
+    /// Since the pairs are contracted one after the other, a pair can use an edge that was created by an earlier contraction.
+    ///
+    /// # Arguments
+    ///
+    /// * `edges` - The (`a`, `b`) pairs to contract, where `b` is merged into `a`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no edge from `a` to `b` when the pair is contracted, or if `a` and `b` are the same node.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use aoc_helper::graph::vec_graph::VecGraph;
+    ///
+    /// let mut graph: VecGraph<usize, u32> = VecGraph::default();
+    ///
+    /// // A corridor of 5 cells, that is walkable in both directions
+    /// let cells = (0..5).map(|i| graph.push_node(i)).collect::<Vec<_>>();
+    /// for pair in cells.windows(2) {
+    ///     graph.add_weighted_edge(pair[0], pair[1], 1);
+    ///     graph.add_weighted_edge(pair[1], pair[0], 1);
+    /// }
+    ///
+    /// // Keep the two ends, and merge everything in between into the first cell
+    /// graph.contract_edges(cells[1..4].iter().map(|&cell| (cells[0], cell)));
+    ///
+    /// assert_eq!(graph.weighted_successors(cells[0]).collect::<Vec<_>>(), vec![(cells[4], &4)]);
+    /// assert_eq!(graph.weighted_successors(cells[4]).collect::<Vec<_>>(), vec![(cells[0], &4)]);
+    /// assert_eq!(graph.node_count(), 2);
+    /// ```
+    pub fn contract_edges<I>(&mut self, edges: I)
+    where
+        I: IntoIterator<Item = (NodeIndex, NodeIndex)>,
+        E: Add<Output = E> + Copy,
+    {
+        // The nodes that have an edge going into each node, so the edges into `b` can be found without scanning the whole graph
+        let mut incoming: HashMap<NodeIndex, HashSet<NodeIndex>> = HashMap::new();
+        for (node, _) in self.live_nodes() {
+            for next in self.successors(node.index) {
+                incoming.entry(next).or_default().insert(node.index);
+            }
+        }
+
+        for (a, b) in edges {
+            assert_ne!(a, b, "Can not contract an edge from a node to itself.");
+
+            let weight_between = |from: NodeIndex, to: NodeIndex| {
+                self.weighted_successors(from)
+                    .find(|(target, _)| *target == to)
+                    .map(|(_, &weight)| weight)
+            };
+
+            let a_to_b = weight_between(a, b).expect("There is no edge between the nodes.");
+            let b_to_a = weight_between(b, a);
+
+            let sources = incoming.remove(&b).unwrap_or_default();
+
+            let outgoing = self
+                .weighted_successors(b)
+                .filter(|(target, _)| *target != a && *target != b)
+                .map(|(target, &weight)| (target, a_to_b + weight))
+                .collect_vec();
+
+            let into_b = match b_to_a {
+                Some(b_to_a) => sources
+                    .iter()
+                    .filter(|&&node| node != a && node != b)
+                    .flat_map(|&node| {
+                        self.weighted_successors(node)
+                            .filter(|(target, _)| *target == b)
+                            .map(move |(_, &weight)| (node, weight + b_to_a))
+                    })
+                    .collect_vec(),
+                None => Vec::new(),
+            };
+
+            for target in self.successors(b).collect_vec() {
+                if let Some(sources) = incoming.get_mut(&target) {
+                    sources.remove(&b);
+                }
+            }
+
+            for (target, weight) in outgoing {
+                self.add_weighted_edge(a, target, weight);
+                incoming.entry(target).or_default().insert(a);
+            }
+
+            for (source, weight) in into_b {
+                self.add_weighted_edge(source, a, weight);
+                incoming.entry(a).or_default().insert(source);
+            }
+
+            // Only `b` itself, and the nodes with an edge to it have to be unlinked
+            self.nodes[b.0].data = None;
+            for source in sources {
+                self.unlink_removed_targets(source);
+            }
+            self.unlink_removed_targets(b);
+        }
+    }
+
+    /// Repeatedly remove the nodes that are connected to at most one other node, until every remaining node has at least two neighbors, and return the number of removed nodes.
+    /// This trims the dead ends of a maze, leaving only its cycles. If the graph is a tree (or a forest), every node is removed.
+    /// Edges are treated as undirected when counting neighbors, and edges from a node to itself are ignored. Removed nodes are replaced with tombstones, as in [`remove_node`](VecGraph::remove_node).
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use aoc_helper::graph::{Graph, vec_graph::VecGraph};
+    /// let mut graph = VecGraph::new();
+    ///
+    /// let a = graph.add_node('a');
+    /// let b = graph.add_node('b');
+    /// let c = graph.add_node('c');
+    /// let tail = graph.add_node('d');
+    ///
+    /// for (x, y) in [(a, b), (b, c), (c, a), (c, tail)] {
+    ///     graph.add_edge(x, y);
+    ///     graph.add_edge(y, x);
+    /// }
+    ///
+    /// assert_eq!(graph.remove_leaves(), 1);
+    /// assert!(graph.get_data(&tail).is_none());
+    /// assert_eq!(graph.node_count(), 3);
+    /// ```
+    pub fn remove_leaves(&mut self) -> usize {
+        let mut neighbors: HashMap<NodeIndex, HashSet<NodeIndex>> = self
+            .live_nodes()
+            .map(|(node, _)| (node.index, HashSet::new()))
+            .collect();
+
+        let links = self
+            .live_nodes()
+            .flat_map(|(node, _)| self.successors(node.index).map(move |next| (node.index, next)))
+            .filter(|(node, next)| node != next)
+            .collect_vec();
+
+        for (node, next) in links {
+            neighbors.get_mut(&node).unwrap().insert(next);
+            neighbors.get_mut(&next).unwrap().insert(node);
+        }
+
+        let mut queue = neighbors
+            .iter()
+            .filter(|(_, adjacent)| adjacent.len() <= 1)
+            .map(|(&node, _)| node)
+            .collect::<VecDeque<_>>();
+        let mut removed = 0;
+
+        while let Some(node) = queue.pop_front() {
+            // A node can be queued more than once, if it lost several neighbors
+            let Some(adjacent) = neighbors.remove(&node) else {
+                continue;
+            };
+
+            self.nodes[node.0].data = None;
+            removed += 1;
+
+            for other in adjacent {
+                if let Some(other_adjacent) = neighbors.get_mut(&other) {
+                    other_adjacent.remove(&node);
+
+                    if other_adjacent.len() <= 1 {
+                        queue.push_back(other);
+                    }
+                }
+            }
+        }
+
+        self.unlink_removed_nodes();
+
+        removed
+    }
+
+    /// Run Dijkstra's algorithm from `start` until every reachable node is found, and return the resulting shortest path tree.
+    /// The tree maps each reachable node to its predecessor on a shortest path from `start`; `start` maps to itself.
+    /// As in [`Graph::dijkstra_with_cost`], the costs are added with [`usize::saturating_add`], so they can not wrap around.
+    /// Use [`reconstruct_from_tree`](super::reconstruct_from_tree) to get the path to any node from the tree, without re-running the search for each target.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - The node where the search starts.
+    /// * `cost_fn` - A function that calculates the cost of traversing given the data stored in a node.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use aoc_helper::graph::{Graph, reconstruct_from_tree, vec_graph::VecGraph};
+    ///
+    /// let mut graph = VecGraph::new();
+    ///
+    /// let start = graph.add_node(0);
+    /// let n1 = graph.add_node(1);
+    /// let n2 = graph.add_node(2);
+    ///
+    /// graph.add_edge(start, n1);
+    /// graph.add_edge(n1, n2);
+    ///
+    /// let tree = graph.shortest_path_tree(start, |&d| d);
+    ///
+    /// assert_eq!(reconstruct_from_tree(&tree, start, n1), vec![start, n1]);
+    /// assert_eq!(reconstruct_from_tree(&tree, start, n2), vec![start, n1, n2]);
+    /// ```
+    pub fn shortest_path_tree<F>(&self, start: NodeIndex, cost_fn: F) -> HashMap<NodeIndex, NodeIndex>
+    where
+        F: Fn(&T) -> usize,
+    {
+        let mut frontier = DoublePriorityQueue::new();
+        frontier.push(start, (0, start));
+
+        let mut came_from = HashMap::new();
+        came_from.insert(start, start);
+
+        let mut cost_so_far = HashMap::new();
+        cost_so_far.insert(start, 0);
+
+        while let Some((current, _)) = frontier.pop_min() {
+            for next in self.successors(current) {
+                let data = self.node_data(&next).unwrap();
+                let new_cost = cost_fn(data).saturating_add(cost_so_far[&current]);
+
+                if !cost_so_far.contains_key(&next) || new_cost < cost_so_far[&next] {
+                    cost_so_far.insert(next, new_cost);
+                    came_from.insert(next, current);
+                    frontier.push(next, (new_cost, next));
+                }
+            }
+        }
+
+        came_from
+    }
+
+    /// Find the minimum cost cycle that starts and ends at `node`, and return it together with its cost.
+    /// The returned path starts and ends with `node`. As with [`dijkstra_with_cost`](Graph::dijkstra_with_cost), the cost is the sum of `cost_fn` over every node on the path except the first one, so each node of the cycle is counted exactly once.
+    /// Returns [`None`] if `node` is not part of any cycle.
+    ///
+    /// # Arguments
+    ///
+    /// * `node` - The node the cycle has to go through.
+    /// * `cost_fn` - A function that calculates the cost of traversing given the data stored in a node.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use aoc_helper::graph::{Graph, vec_graph::VecGraph};
+    ///
+    /// let mut graph = VecGraph::new();
+    ///
+    /// let n0 = graph.add_node(1);
+    /// let n1 = graph.add_node(1);
+    ///
+    /// graph.add_edge(n0, n1);
+    /// graph.add_edge(n1, n0);
+    ///
+    /// assert_eq!(graph.shortest_cycle_through(n0, |&d| d), Some((vec![n0, n1, n0], 2)));
+    /// ```
+    pub fn shortest_cycle_through<F>(&self, node: NodeIndex, cost_fn: F) -> Option<(Vec<NodeIndex>, usize)>
+    where
+        F: Fn(&T) -> usize,
+        Self: Graph<DataType = T, NodeReference = NodeIndex>,
+    {
+        self.successors(node)
+            .filter_map(|next| {
+                let (path, cost) = self.dijkstra_with_cost(next, node, &cost_fn)?;
+                let next_cost = cost_fn(self.get_data(&next).unwrap());
+
+                let mut cycle = vec![node];
+                cycle.extend(path);
+
+                Some((cycle, cost + next_cost))
+            })
+            .min_by_key(|(_, cost)| *cost)
+    }
+
+    /// Return every node whose cheapest path from `start` costs exactly `target_cost`, ordered by [`NodeIndex`].
+    /// This is the weighted version of a single layer of [`bfs_layers`](VecGraph::bfs_layers). As with [`dijkstra_with_cost`](Graph::dijkstra_with_cost), the cost of a path is the sum of `cost_fn` over every node on the path except `start`.
+    /// The search stops as soon as every node that costs at most `target_cost` has been found.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - The node where the search starts. It is returned if `target_cost` is 0.
+    /// * `cost_fn` - A function that calculates the cost of entering a node given its data.
+    /// * `target_cost` - The cost of the returned nodes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use aoc_helper::graph::{Graph, vec_graph::VecGraph};
+    ///
+    /// let mut graph = VecGraph::new();
+    ///
+    /// let start = graph.add_node(0);
+    /// let n1 = graph.add_node(2);
+    /// let n2 = graph.add_node(1);
+    ///
+    /// graph.add_edge(start, n1);
+    /// graph.add_edge(start, n2);
+    /// graph.add_edge(n2, n1);
+    ///
+    /// assert_eq!(graph.nodes_at_cost(start, |&d| d, 2), vec![n1]);
+    /// assert!(graph.nodes_at_cost(start, |&d| d, 3).is_empty());
+    /// ```
+    pub fn nodes_at_cost<F>(&self, start: NodeIndex, cost_fn: F, target_cost: usize) -> Vec<NodeIndex>
+    where
+        F: Fn(&T) -> usize,
+    {
+        let mut frontier = DoublePriorityQueue::new();
+        frontier.push(start, (0, start));
+
+        let mut cost_so_far = HashMap::new();
+        cost_so_far.insert(start, 0);
+
+        // Nodes with the same cost are popped in the order of their index, so `found` is already sorted
+        let mut found = Vec::new();
+
+        while let Some((current, (cost, _))) = frontier.pop_min() {
+            if cost > target_cost {
+                break;
+            }
+            if cost == target_cost {
+                found.push(current);
+            }
+
+            for next in self.successors(current) {
+                let data = self.node_data(&next).unwrap();
+                let new_cost = cost_fn(data).saturating_add(cost);
+
+                if !cost_so_far.contains_key(&next) || new_cost < cost_so_far[&next] {
+                    cost_so_far.insert(next, new_cost);
+                    frontier.push(next, (new_cost, next));
+                }
+            }
+        }
+
+        found
+    }
+
+    /// Move every node and edge of `other` into this graph, and return the new [`NodeIndex`] of each node of `other`.
+    /// The node that had `NodeIndex(i)` in `other` is found at index `i` of the returned vector, so edges between the two parts can be added afterwards.
+    /// The edges of `other` keep their order, so [`successors`](VecGraph::successors) yields the same nodes in the same order as before (with the new indices).
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The graph that is appended to this graph.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use aoc_helper::graph::{Graph, vec_graph::VecGraph};
+    /// let mut graph = VecGraph::new();
+    /// let a = graph.add_node('a');
+    ///
+    /// let mut other = VecGraph::new();
+    /// let b = other.add_node('b');
+    /// let c = other.add_node('c');
+    /// other.add_edge(b, c);
+    ///
+    /// let moved = graph.append(other);
+    /// graph.add_edge(a, moved[b.0]);
+    ///
+    /// assert_eq!(graph.get_data(&moved[c.0]), Some(&'c'));
+    /// assert_eq!(graph.get_neighbors(&moved[b.0]), vec![moved[c.0]]);
+    /// assert_eq!(graph.get_neighbors(&a), vec![moved[b.0]]);
+    /// ```
+    pub fn append(&mut self, other: VecGraph<T, E>) -> Vec<NodeIndex> {
+        let node_offset = self.nodes.len();
+        let edge_offset = self.edges.len();
+
+        let move_edge = |edge: EdgeIndex| EdgeIndex(edge.0 + edge_offset);
+        let move_node = |node: NodeIndex| NodeIndex(node.0 + node_offset);
+
+        self.edges.extend(other.edges.into_iter().map(|edge| EdgeData {
+            target: move_node(edge.target),
+            next_outgoing_edge: edge.next_outgoing_edge.map(move_edge),
+            weight: edge.weight,
+        }));
+
+        // Removed nodes are moved as well, so that the indices of `other` can be offset by the same amount
+        self.nodes.extend(other.nodes.into_iter().map(|node| NodeData {
+            data: node.data,
+            index: move_node(node.index),
+            first_outgoing_edge: node.first_outgoing_edge.map(move_edge),
+            out_degree: node.out_degree,
+        }));
+
+        (node_offset..self.nodes.len()).map(NodeIndex).collect()
+    }
+
+    /// Return a copy of this graph where the direction of every edge is reversed, i.e. there is an edge from `b` to `a` with the same weight for every edge from `a` to `b`.
+    /// Every node keeps its [`NodeIndex`], including the removed ones, so indices can be used in both graphs.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use aoc_helper::graph::{Graph, vec_graph::VecGraph};
+    /// let mut graph = VecGraph::new();
+    ///
+    /// let n0 = graph.add_node(0);
+    /// let n1 = graph.add_node(1);
+    ///
+    /// graph.add_edge(n0, n1);
+    ///
+    /// let transposed = graph.transpose();
+    ///
+    /// assert!(transposed.get_neighbors(&n0).is_empty());
+    /// assert_eq!(transposed.get_neighbors(&n1), vec![n0]);
+    /// ```
+    pub fn transpose(&self) -> VecGraph<T, E>
+    where
+        T: Clone,
+        E: Clone,
+    {
+        let mut transposed = VecGraph {
+            nodes: self
+                .nodes
+                .iter()
+                .map(|node| NodeData {
+                    data: node.data.clone(),
+                    index: node.index,
+                    first_outgoing_edge: None,
+                    out_degree: 0,
+                })
+                .collect(),
+            edges: Vec::with_capacity(self.edges.len()),
+        };
+
+        for (source, _) in self.iter_data() {
+            // Add the edges in the order they were added to this graph
+            let edges = self.successor_edges(source).collect_vec();
+
+            for (edge_index, target) in edges.into_iter().rev() {
+                transposed.add_weighted_edge(target, source, self.edges[edge_index.0].weight.clone());
+            }
+        }
+
+        transposed
+    }
+
+    /// Search for the cheapest path between `start` and `target` with a bidirectional Dijkstra search, and return the path together with its cost.
+    /// One search runs forwards from `start`, and another runs backwards from `target` on the [`transpose`](VecGraph::transpose)d graph. The search stops as soon as the two searches can not find a cheaper path than the best one where they met, so on large graphs it usually expands far fewer nodes than [`dijkstra_with_cost`](Graph::dijkstra_with_cost).
+    /// As with [`dijkstra_with_cost`](Graph::dijkstra_with_cost), the cost is the sum of `cost_fn` over every node on the path except `start`, and the costs are added with [`usize::saturating_add`].
+    /// If there are several cheapest paths, the returned path can differ from the one [`dijkstra_with_cost`](Graph::dijkstra_with_cost) returns.
+    /// Returns [`None`] if `target` cannot be reached from `start`, or if either of them is not a node of this graph.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - The node where the search starts.
+    /// * `target` - The node where the search ends.
+    /// * `cost_fn` - A function that calculates the cost of entering a node given its data.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use aoc_helper::graph::{Graph, vec_graph::VecGraph};
+    ///
+    /// let mut graph = VecGraph::new();
+    ///
+    /// let start = graph.add_node(0);
+    /// let n1 = graph.add_node(1000);
+    /// let n2 = graph.add_node(1);
+    /// let destination = graph.add_node(3);
+    ///
+    /// graph.add_edge(start, n1);
+    /// graph.add_edge(start, n2);
+    /// graph.add_edge(n1, destination);
+    /// graph.add_edge(n2, destination);
+    ///
+    /// let (path, cost) = graph.dijkstra_bidirectional(start, destination, |&d| d).unwrap();
+    ///
+    /// assert_eq!(&path, &[start, n2, destination]);
+    /// assert_eq!(cost, 4);
+    /// ```
+    pub fn dijkstra_bidirectional<F>(
+        &self,
+        start: NodeIndex,
+        target: NodeIndex,
+        cost_fn: F,
+    ) -> Option<(Vec<NodeIndex>, usize)>
+    where
+        T: Clone,
+        E: Clone,
+        F: Fn(&T) -> usize,
+    {
+        self.node_data(&start)?;
+        self.node_data(&target)?;
+
+        if start == target {
+            return Some((vec![start], 0));
+        }
+
+        const FORWARD: usize = 0;
+        const BACKWARD: usize = 1;
+
+        let reversed = self.transpose();
+        let graphs = [self, &reversed];
+
+        let mut frontiers = [DoublePriorityQueue::new(), DoublePriorityQueue::new()];
+        frontiers[FORWARD].push(start, (0usize, start));
+        frontiers[BACKWARD].push(target, (0, target));
+
+        let mut came_from = [HashMap::from([(start, start)]), HashMap::from([(target, target)])];
+        let mut cost_so_far = [HashMap::from([(start, 0)]), HashMap::from([(target, 0)])];
+
+        // The cheapest path found so far, and the node where the two searches met on it
+        let mut best: Option<(usize, NodeIndex)> = None;
+
+        while let (Some((_, &(forward_min, _))), Some((_, &(backward_min, _)))) =
+            (frontiers[FORWARD].peek_min(), frontiers[BACKWARD].peek_min())
+        {
+            // Every path that has not been found yet costs at least as much as the cheapest nodes of the two frontiers together
+            if best.is_some_and(|(cost, _)| forward_min.saturating_add(backward_min) >= cost) {
+                break;
+            }
+
+            let side = if forward_min <= backward_min { FORWARD } else { BACKWARD };
+            let (current, (current_cost, _)) = frontiers[side].pop_min().unwrap();
+
+            for next in graphs[side].successors(current) {
+                // Both searches count the cost of the node that is later on the original path
+                let entered = if side == FORWARD { next } else { current };
+                let new_cost = current_cost.saturating_add(cost_fn(self.node_data(&entered).unwrap()));
+
+                if cost_so_far[side].get(&next).is_none_or(|&cost| new_cost < cost) {
+                    cost_so_far[side].insert(next, new_cost);
+                    came_from[side].insert(next, current);
+                    frontiers[side].push(next, (new_cost, next));
+
+                    if let Some(&other_cost) = cost_so_far[1 - side].get(&next) {
+                        let total = new_cost.saturating_add(other_cost);
+
+                        if best.is_none_or(|(cost, _)| total < cost) {
+                            best = Some((total, next));
+                        }
+                    }
+                }
+            }
+        }
+
+        let (cost, meeting) = best?;
+
+        let mut path = reconstruct_path(&came_from[FORWARD], start, meeting);
+        let mut current = meeting;
+        while current != target {
+            current = came_from[BACKWARD][&current];
+            path.push(current);
+        }
+
+        Some((path, cost))
+    }
+
+    /// Find every minimum cost path from `start` to `target`, using Dijkstra's algorithm that keeps track of all predecessors that reach a node at the same cost.
+    /// As with [`dijkstra_with_cost`](Graph::dijkstra_with_cost), the cost of a path is the sum of `cost_fn` over every node on the path except `start`.
+    /// Returns an empty [`Vec`] if `target` cannot be reached from `start`.
+    ///
+    /// Note that the number of shortest paths can grow exponentially with the size of the graph (e.g. in an open grid), so only use this when the number of paths is known to be small.
+    /// To only count the paths, prefer an approach that does not enumerate them. Nodes with a cost of 0 can create cycles of equal cost; these are skipped, so every returned path is simple.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - The node where the paths start.
+    /// * `target` - The node where the paths end.
+    /// * `cost_fn` - A function that calculates the cost of traversing given the data stored in a node.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use aoc_helper::graph::{Graph, vec_graph::VecGraph};
+    ///
+    /// let mut graph = VecGraph::new();
+    ///
+    /// let start = graph.add_node(0);
+    /// let a = graph.add_node(1);
+    /// let b = graph.add_node(1);
+    /// let c = graph.add_node(5);
+    /// let target = graph.add_node(1);
+    ///
+    /// for node in [a, b, c] {
+    ///     graph.add_edge(start, node);
+    ///     graph.add_edge(node, target);
+    /// }
+    ///
+    /// let paths = graph.all_shortest_paths(start, target, |&d| d);
+    ///
+    /// assert_eq!(paths.len(), 2);
+    /// assert!(paths.contains(&vec![start, a, target]));
+    /// assert!(paths.contains(&vec![start, b, target]));
+    /// ```
+    pub fn all_shortest_paths<F>(&self, start: NodeIndex, target: NodeIndex, cost_fn: F) -> Vec<Vec<NodeIndex>>
+    where
+        F: Fn(&T) -> usize,
+    {
+        let mut frontier = DoublePriorityQueue::new();
+        frontier.push(start, (0, start));
+
+        let mut predecessors: HashMap<NodeIndex, Vec<NodeIndex>> = HashMap::new();
+
+        let mut cost_so_far = HashMap::new();
+        cost_so_far.insert(start, 0);
+
+        while let Some((current, (cost, _))) = frontier.pop_min() {
+            // Nodes that are more expensive than the target can not be part of a shortest path to it
+            if cost_so_far.get(&target).is_some_and(|&best| cost > best) {
+                break;
+            }
+
+            for next in self.successors(current) {
+                let new_cost = cost_fn(self.node_data(&next).unwrap()).saturating_add(cost);
+
+                match cost_so_far.get(&next) {
+                    Some(&known) if new_cost > known => {}
+                    Some(&known) if new_cost == known => {
+                        let preds = predecessors.entry(next).or_default();
+                        if !preds.contains(&current) {
+                            preds.push(current);
+                        }
+                    }
+                    _ => {
+                        cost_so_far.insert(next, new_cost);
+                        predecessors.insert(next, vec![current]);
+                        frontier.push(next, (new_cost, next));
+                    }
+                }
+            }
+        }
+
+        if !cost_so_far.contains_key(&target) {
+            return Vec::new();
+        }
+
+        // Walk backwards from the target, branching at every node with more than one predecessor
+        let mut paths = Vec::new();
+        let mut stack = vec![vec![target]];
+
+        while let Some(path) = stack.pop() {
+            let last = *path.last().unwrap();
+
+            if last == start {
+                paths.push(path.into_iter().rev().collect());
+                continue;
+            }
+
+            for &previous in predecessors.get(&last).into_iter().flatten() {
+                if !path.contains(&previous) {
+                    let mut extended = path.clone();
+                    extended.push(previous);
+                    stack.push(extended);
+                }
+            }
+        }
+
+        paths
+    }
+
+    /// Run a breadth-first search from `start`, and return the first node whose data satisfies `predicate`, together with the number of steps needed to reach it.
+    /// `start` itself is checked first, at a distance of 0. The search stops as soon as a matching node is found.
+    /// Returns [`None`] if no reachable node matches.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - The node where the search starts.
+    /// * `predicate` - A closure that is applied to the data of each visited node.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use aoc_helper::graph::{Graph, vec_graph::VecGraph};
+    ///
+    /// let mut graph = VecGraph::new();
+    ///
+    /// let start = graph.add_node('.');
+    /// let n1 = graph.add_node('.');
+    /// let gold = graph.add_node('G');
+    ///
+    /// graph.add_edge(start, n1);
+    /// graph.add_edge(n1, gold);
+    ///
+    /// assert_eq!(graph.nearest(start, |&c| c == 'G'), Some((gold, 2)));
+    /// assert_eq!(graph.nearest(start, |&c| c == 'X'), None);
+    /// ```
+    pub fn nearest<P>(&self, start: NodeIndex, predicate: P) -> Option<(NodeIndex, usize)>
+    where
+        P: Fn(&T) -> bool,
+    {
+        let mut visited = HashSet::new();
+        visited.insert(start);
+
+        let mut queue = VecDeque::new();
+        queue.push_back((start, 0));
+
+        while let Some((current, distance)) = queue.pop_front() {
+            if self.node_data(&current).is_some_and(&predicate) {
+                return Some((current, distance));
+            }
+
+            for next in self.successors(current) {
+                if visited.insert(next) {
+                    queue.push_back((next, distance + 1));
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Run a breadth-first search from `start`, and return the nodes grouped by their distance from `start`.
+    /// The entry at index `d` holds every node that is first reached after `d` steps, so the first entry only contains `start`.
+    /// Nodes that can not be reached from `start` are not included.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - The node where the search starts.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use aoc_helper::graph::{Graph, vec_graph::VecGraph};
+    ///
+    /// let mut graph = VecGraph::new();
+    ///
+    /// let start = graph.add_node(0);
+    /// let a = graph.add_node(1);
+    /// let b = graph.add_node(1);
+    /// let c = graph.add_node(2);
+    ///
+    /// graph.add_edge(start, a);
+    /// graph.add_edge(start, b);
+    /// graph.add_edge(a, c);
+    /// graph.add_edge(b, c);
+    ///
+    /// assert_eq!(graph.bfs_layers(start), vec![vec![start], vec![b, a], vec![c]]);
+    /// ```
+    pub fn bfs_layers(&self, start: NodeIndex) -> Vec<Vec<NodeIndex>> {
+        let mut visited = HashSet::from([start]);
+        let mut layers = Vec::new();
+        let mut frontier = vec![start];
+
+        while !frontier.is_empty() {
+            let next = frontier
+                .iter()
+                .flat_map(|&node| self.successors(node))
+                .filter(|&node| visited.insert(node))
+                .collect();
+
+            layers.push(frontier);
+            frontier = next;
+        }
+
+        layers
+    }
+
+    /// Walk the loop that goes through `start`, and return its nodes in order, starting with `start`. The first node is not repeated at the end.
+    /// Every node on the loop must have exactly one way forward, apart from the node the walk came from, like the pipes in AoC 2023 Day 10.
+    /// This works both for directed rings, and for undirected rings where every edge is added in both directions.
+    /// The walk is tried along every successor of `start`, so `start` itself may have more neighbors than the loop needs (like the `S` tile of Day 10). If several walks lead back to `start`, the longest loop is returned.
+    /// Two nodes with edges to each other count as a loop, so a single undirected edge is returned as a loop of two nodes, unless `start` is also on a longer loop.
+    /// Returns [`None`] if `start` is not on a simple loop, e.g. if every walk reaches a dead end, a fork, or a loop that does not lead back to `start`.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - The node the loop has to contain.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use aoc_helper::graph::{Graph, vec_graph::VecGraph};
+    ///
+    /// let mut graph = VecGraph::new();
+    ///
+    /// let a = graph.add_node('a');
+    /// let b = graph.add_node('b');
+    /// let c = graph.add_node('c');
+    /// let d = graph.add_node('d');
+    ///
+    /// graph.add_edge(a, b);
+    /// graph.add_edge(b, c);
+    /// graph.add_edge(c, a);
+    /// graph.add_edge(d, a);
+    ///
+    /// assert_eq!(graph.find_loop_containing(a), Some(vec![a, b, c]));
+    /// assert_eq!(graph.find_loop_containing(d), None);
+    /// ```
+    pub fn find_loop_containing(&self, start: NodeIndex) -> Option<Vec<NodeIndex>> {
+        self.successors(start)
+            .filter_map(|first| self.walk_loop(start, first))
+            .reduce(|longest, path| if path.len() > longest.len() { path } else { longest })
+    }
+
+    /// Return the nodes of the graph in topological order, i.e. every node comes before all of the nodes it has an edge to.
+    /// When more than one node is ready to be added to the order, `tiebreak` decides which one comes first: the node whose data compares as the smallest is chosen.
+    ///
+    /// # Arguments
+    ///
+    /// * `tiebreak` - A comparator applied to the data of the nodes that are ready at the same time.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`GraphError::Cycle`] if the graph contains a cycle, and therefore has no topological order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use aoc_helper::graph::{Graph, vec_graph::VecGraph};
+    ///
+    /// let mut graph = VecGraph::new();
+    ///
+    /// let c = graph.add_node('C');
+    /// let b = graph.add_node('B');
+    /// let a = graph.add_node('A');
+    ///
+    /// graph.add_edge(c, a);
+    ///
+    /// assert_eq!(graph.topological_order_with_ties(|x, y| x.cmp(y)), Ok(vec![b, c, a]));
+    /// ```
+    pub fn topological_order_with_ties<F>(&self, tiebreak: F) -> Result<Vec<NodeIndex>, GraphError>
+    where
+        F: Fn(&T, &T) -> Ordering,
+    {
+        let mut in_degree = vec![0; self.nodes.len()];
+        for (node, _) in self.live_nodes() {
+            for next in self.successors(node.index) {
+                in_degree[next.0] += 1;
+            }
+        }
+
+        let mut ready = self
+            .live_nodes()
+            .filter(|(node, _)| in_degree[node.index.0] == 0)
+            .map(|(node, _)| node.index)
+            .collect_vec();
+
+        let mut order = Vec::new();
+
+        while let Some((position, _)) = ready
+            .iter()
+            .enumerate()
+            .min_by(|(_, a), (_, b)| tiebreak(self.node_data(a).unwrap(), self.node_data(b).unwrap()))
+        {
+            let current = ready.swap_remove(position);
+            order.push(current);
+
+            for next in self.successors(current) {
+                in_degree[next.0] -= 1;
+                if in_degree[next.0] == 0 {
+                    ready.push(next);
+                }
+            }
+        }
+
+        if order.len() == self.node_count() {
+            Ok(order)
+        } else {
+            Err(GraphError::Cycle)
+        }
+    }
+
+    /// Count the number of distinct directed paths from `start` to `target`.
+    /// The count for each node is only calculated once, so this works even when the number of paths is far too large to enumerate them.
+    /// If there are multiple edges between two nodes, each of them counts as a separate path.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - The node where the paths start.
+    /// * `target` - The node where the paths end.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the graph contains a cycle, since the number of paths would be infinite.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use aoc_helper::graph::{Graph, vec_graph::VecGraph};
+    ///
+    /// let mut graph = VecGraph::new();
+    ///
+    /// let start = graph.add_node(0);
+    /// let left = graph.add_node(1);
+    /// let right = graph.add_node(2);
+    /// let target = graph.add_node(3);
+    ///
+    /// graph.add_edge(start, left);
+    /// graph.add_edge(start, right);
+    /// graph.add_edge(left, target);
+    /// graph.add_edge(right, target);
+    ///
+    /// assert_eq!(graph.count_paths(start, target), 2);
+    /// ```
+    pub fn count_paths(&self, start: NodeIndex, target: NodeIndex) -> u64 {
+        let order = self
+            .topological_order_with_ties(|_, _| Ordering::Equal)
+            .expect("Paths can only be counted in an acyclic graph.");
+
+        let mut paths = vec![0; self.nodes.len()];
+        paths[target.0] = 1;
+
+        // Every successor of a node comes after it in the topological order, so its count is already known
+        for node in order.into_iter().rev().filter(|&n| n != target) {
+            paths[node.0] = self.successors(node).map(|next| paths[next.0]).sum();
+        }
+
+        paths[start.0]
+    }
+
+    /// Color the nodes of the graph, so that no two adjacent nodes share a color, and return the color of each node.
+    /// Colors are numbered from 0. Edges are treated as undirected, and self-loops are ignored.
+    ///
+    /// The nodes are colored greedily in order of descending degree: each node gets the smallest color not used by its already colored neighbors.
+    /// This does not always use the smallest possible number of colors, but gives an upper bound on it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use aoc_helper::graph::{Graph, vec_graph::VecGraph};
+    ///
+    /// let mut graph = VecGraph::new();
+    ///
+    /// let a = graph.add_node('a');
+    /// let b = graph.add_node('b');
+    /// let c = graph.add_node('c');
+    ///
+    /// graph.add_edge(a, b);
+    /// graph.add_edge(b, c);
+    ///
+    /// let colors = graph.greedy_coloring();
+    ///
+    /// assert_eq!(colors[&b], 0);
+    /// assert_eq!(colors[&a], 1);
+    /// assert_eq!(colors[&c], 1);
+    /// ```
+    pub fn greedy_coloring(&self) -> HashMap<NodeIndex, usize> {
+        let mut adjacent: HashMap<NodeIndex, HashSet<NodeIndex>> = self
+            .live_nodes()
+            .map(|(node, _)| (node.index, HashSet::new()))
+            .collect();
+
+        for (node, _) in self.live_nodes() {
+            for next in self.successors(node.index).filter(|&next| next != node.index) {
+                adjacent.get_mut(&node.index).unwrap().insert(next);
+                adjacent.get_mut(&next).unwrap().insert(node.index);
+            }
+        }
+
+        let mut order = adjacent.keys().copied().collect_vec();
+        order.sort_by_key(|node| (Reverse(adjacent[node].len()), *node));
+
+        let mut colors = HashMap::new();
+
+        for node in order {
+            let used = adjacent[&node]
+                .iter()
+                .filter_map(|neighbor| colors.get(neighbor))
+                .collect::<HashSet<_>>();
+
+            let color = (0..).find(|color| !used.contains(color)).unwrap();
+            colors.insert(node, color);
+        }
+
+        colors
+    }
+
+    /// Return the indices of the nodes in this graph, ordered by their data according to `cmp`.
+    /// The sort is stable, so nodes with equal data keep their index order. The graph itself is not modified.
+    ///
+    /// # Arguments
+    ///
+    /// * `cmp` - A comparator applied to the data of the nodes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use aoc_helper::graph::{Graph, vec_graph::VecGraph};
+    ///
+    /// let mut graph = VecGraph::new();
+    ///
+    /// let n0 = graph.add_node(3);
+    /// let n1 = graph.add_node(1);
+    /// let n2 = graph.add_node(2);
+    ///
+    /// assert_eq!(graph.nodes_sorted_by(|a, b| a.cmp(b)), vec![n1, n2, n0]);
+    /// ```
+    pub fn nodes_sorted_by<F>(&self, cmp: F) -> Vec<NodeIndex>
+    where
+        F: Fn(&T, &T) -> Ordering,
+    {
+        let mut nodes = self.live_nodes().collect_vec();
+        nodes.sort_by(|(_, a), (_, b)| cmp(a, b));
+
+        nodes.into_iter().map(|(node, _)| node.index).collect()
+    }
+
+    /// Walk from `start` along the edge to `first`, and return the visited nodes if the walk leads back to `start` without any forks.
+    fn walk_loop(&self, start: NodeIndex, first: NodeIndex) -> Option<Vec<NodeIndex>> {
+        let mut previous = start;
+        let mut current = first;
+
+        let mut path = vec![start];
+        let mut visited = HashSet::from([start]);
+
+        while current != start {
+            if !visited.insert(current) {
+                return None;
+            }
+            path.push(current);
+
+            let mut forward = self.successors(current).filter(|&next| next != previous);
+            let next = match forward.next() {
+                Some(next) => next,
+                // Two nodes that point at each other form a loop, even though the way back leads through the previous node
+                None if previous == start && self.successors(current).any(|next| next == start) => start,
+                None => return None,
+            };
+
+            if forward.next().is_some() {
+                return None;
+            }
+
+            previous = current;
+            current = next;
+        }
+
+        Some(path)
+    }
+
+    /// Depth-first search used by [`longest_simple_path`](VecGraph::longest_simple_path). `visited` holds the nodes on the current path.
+    fn longest_simple_path_from<F>(
+        &self,
+        current: NodeIndex,
+        target: NodeIndex,
+        edge_weight_fn: &F,
+        visited: &mut NodeBitSet,
+    ) -> Option<usize>
+    where
+        F: Fn(&E) -> usize,
+    {
+        if current == target {
+            return Some(0);
+        }
+
+        let mut longest = None;
+
+        for (next, weight) in self.weighted_successors(current) {
+            if !visited.insert(next) {
+                continue;
+            }
+
+            if let Some(rest) = self.longest_simple_path_from(next, target, edge_weight_fn, visited) {
+                longest = longest.max(Some(rest + edge_weight_fn(weight)));
+            }
+            visited.remove(next);
+        }
+
+        longest
+    }
+
+    /// Iterate over the nodes that have not been removed, together with their data.
+    fn live_nodes(&self) -> impl Iterator<Item = (&NodeData<T>, &T)> {
+        self.nodes
+            .iter()
+            .filter_map(|node| node.data.as_ref().map(|data| (node, data)))
+    }
+
+    /// Drop every edge that starts or ends at a removed node from the outgoing edge lists.
+    /// The edges themselves stay in `edges`, so that the [`EdgeIndex`] of the remaining edges is not changed.
+    fn unlink_removed_nodes(&mut self) {
+        for i in 0..self.nodes.len() {
+            self.unlink_removed_targets(NodeIndex(i));
+        }
+    }
+
+    /// Drop the edges that end at a removed node from the outgoing edge list of `node`. A removed `node` loses all of its outgoing edges.
+    fn unlink_removed_targets(&mut self, node: NodeIndex) {
+        let i = node.0;
+
+        if self.nodes[i].data.is_none() {
+            self.nodes[i].first_outgoing_edge = None;
+            self.nodes[i].out_degree = 0;
+            return;
+        }
+
+        let mut kept = Vec::new();
+        let mut current = self.nodes[i].first_outgoing_edge;
+
+        while let Some(edge_index) = current {
+            let edge = &self.edges[edge_index.0];
+            if self.nodes[edge.target.0].data.is_some() {
+                kept.push(edge_index);
+            }
+            current = edge.next_outgoing_edge;
+        }
+
+        self.nodes[i].first_outgoing_edge = kept.first().copied();
+        self.nodes[i].out_degree = kept.len();
+        for (j, edge_index) in kept.iter().enumerate() {
+            self.edges[edge_index.0].next_outgoing_edge = kept.get(j + 1).copied();
+        }
+    }
+}
+
+impl<'graph, T, E> Iterator for Successors<'graph, T, E> {
+    type Item = NodeIndex;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_edge().map(|(_, target)| target)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'graph, T, E> ExactSizeIterator for Successors<'graph, T, E> {}
+
+impl<'graph, T, E> Successors<'graph, T, E> {
+    // Step along the list of outgoing edges, and return the current edge together with its target
+    fn next_edge(&mut self) -> Option<(EdgeIndex, NodeIndex)> {
+        let edge_index = self.current_edge_index?;
+
+        if let Some(edge) = self.graph.edges.get(edge_index.0) {
+            self.current_edge_index = edge.next_outgoing_edge;
+            self.remaining -= 1;
+            Some((edge_index, edge.target))
+        } else {
+            panic!("Edge not found!");
+        }
+    }
+}
+
+pub struct Successors<'graph, T, E = ()> {
+    graph: &'graph VecGraph<T, E>,
+    current_edge_index: Option<EdgeIndex>,
+    remaining: usize,
+}
+
+/// The order in which [`VecGraph::neighbors_ordered`] returns the neighbors of a node.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NeighborOrder {
+    /// The order in which the edges were added.
+    InsertionOrder,
+    /// The most recently added edge first. This is the order of [`VecGraph::successors`].
+    ReverseInsertion,
+    /// Ascending by [`NodeIndex`], independent of the order of the edges.
+    ByIndex,
+}
+
+#[derive(Clone)]
+struct NodeData<T> {
+    data: Option<T>, // None if the node has been removed
+
+    index: NodeIndex,
+    first_outgoing_edge: Option<EdgeIndex>,
+    out_degree: usize, // The number of edges in the outgoing edge list
+}
+
+#[derive(Clone)]
+struct EdgeData<E> {
+    target: NodeIndex,
+    next_outgoing_edge: Option<EdgeIndex>,
+    weight: E,
+}
+
+#[cfg(test)]
+pub mod test {
+    use std::collections::HashMap;
+
+    use regex::Regex;
+
+    use crate::{
+        direction::Direction,
+        geometry::point::Point2D,
+        graph::{grid::Grid, reconstruct_from_tree},
+        search::search_states,
+    };
+
+    use super::*;
+
+    #[test]
+    fn graph_creation_works() {
+        let mut graph: VecGraph<usize> = VecGraph::new();
+
+        let n0 = graph.add_node(1);
+        let n1 = graph.add_node(2);
+        let n2 = graph.add_node(3);
+        let n3 = graph.add_node(4);
+
+        graph.add_edge(n0, n1);
+        graph.add_edge(n1, n2);
+        graph.add_edge(n0, n3);
+        graph.add_edge(n3, n2);
+
+        let s1 = graph.successors(n0).collect::<Vec<_>>();
+        assert_eq!(&s1, &[n3, n1]);
+
+        graph.add_edge(n3, n0);
+        graph.add_edge(n3, n3);
+
+        let s2 = graph.successors(n3).collect::<Vec<_>>();
+        assert_eq!(&s2, &[n3, n0, n2]);
+    }
+
+    #[test]
+    fn graphs_with_different_edge_order_are_equal() {
+        let mut g1 = VecGraph::new();
+        let mut g2 = VecGraph::new();
+
+        let a1 = g1.add_node("a");
+        let b1 = g1.add_node("b");
+        let c1 = g1.add_node("c");
+
+        let a2 = g2.add_node("a");
+        let b2 = g2.add_node("b");
+        let c2 = g2.add_node("c");
+
+        g1.add_edge(a1, b1);
+        g1.add_edge(a1, c1);
+        g1.add_edge(b1, c1);
+
+        g2.add_edge(b2, c2);
+        g2.add_edge(a2, c2);
+        g2.add_edge(a2, b2);
+
+        assert!(g1 == g2);
+
+        g2.add_edge(c2, a2);
+
+        assert!(g1 != g2);
+
+        let mut g3 = VecGraph::new();
+        let a3 = g3.add_node("a");
+        let b3 = g3.add_node("b");
+        let c3 = g3.add_node("x");
+
+        g3.add_edge(a3, b3);
+        g3.add_edge(a3, c3);
+        g3.add_edge(b3, c3);
+
+        assert!(g1 != g3);
+    }
+
+    #[test]
+    fn weighted_graphs_compare_weights() {
+        let build = |weights: [u32; 3]| {
+            let mut graph: VecGraph<char, u32> = VecGraph::default();
+
+            let a = graph.push_node('a');
+            let b = graph.push_node('b');
+
+            for weight in weights {
+                graph.add_weighted_edge(a, b, weight);
+            }
+
+            graph
+        };
+
+        assert!(build([1, 2, 2]) == build([2, 1, 2]));
+        assert!(build([1, 2, 2]) != build([1, 1, 2]));
+        assert!(build([1, 2, 3]) != build([1, 2, 4]));
+    }
+
+    #[test]
+    fn clone_is_independent_of_original() {
+        let mut graph = VecGraph::new();
+
+        let n0 = graph.add_node(0);
+        let n1 = graph.add_node(1);
+        graph.add_edge(n0, n1);
+
+        let mut clone = graph.clone();
+        assert!(clone == graph);
+
+        *clone.get_data_mut(&n0).unwrap() = 100;
+        let n2 = clone.add_node(2);
+        clone.add_edge(n1, n2);
+
+        assert_eq!(*graph.get_data(&n0).unwrap(), 0);
+        assert!(graph.get_data(&n2).is_none());
+        assert!(graph.get_neighbors(&n1).is_empty());
+
+        assert_eq!(*clone.get_data(&n0).unwrap(), 100);
+        assert_eq!(&clone.get_neighbors(&n1), &[n2]);
+    }
+
+    #[test]
+    fn successor_edges_returns_edge_indices_in_insertion_order() {
+        let mut graph = VecGraph::new();
+
+        let n0 = graph.add_node(0);
+        let n1 = graph.add_node(1);
+        let n2 = graph.add_node(2);
+        let n3 = graph.add_node(3);
+
+        graph.add_edge(n0, n1); // EdgeIndex(0)
+        graph.add_edge(n1, n2); // EdgeIndex(1)
+        graph.add_edge(n0, n2); // EdgeIndex(2)
+        graph.add_edge(n0, n3); // EdgeIndex(3)
+
+        let mut edges = graph.successor_edges(n0).collect_vec();
+        edges.reverse(); // Outgoing edges are stored most recent first
+
+        assert_eq!(
+            &edges,
+            &[(EdgeIndex(0), n1), (EdgeIndex(2), n2), (EdgeIndex(3), n3)]
+        );
+
+        // The targets are the same as the ones returned by `successors`
+        let targets = graph.successor_edges(n0).map(|(_, t)| t).collect_vec();
+        assert_eq!(targets, graph.successors(n0).collect_vec());
+
+        assert_eq!(
+            graph.successor_edges(n1).collect_vec(),
+            vec![(EdgeIndex(1), n2)]
+        );
+        assert_eq!(graph.successor_edges(n3).count(), 0);
+    }
+
+    #[test]
+    fn nearest_finds_closest_match() {
+        // 0 -> 1 -> 2 -> 3(x)
+        // 0 -> 4 -> 5(x)
+        // 0 -> 6 -> 7 -> 8 -> 9(x)
+        let mut graph = VecGraph::new();
+
+        let nodes = (0..10).map(|i| graph.add_node(i)).collect_vec();
+        for (a, b) in [(0, 1), (1, 2), (2, 3), (0, 4), (4, 5), (0, 6), (6, 7), (7, 8), (8, 9)] {
+            graph.add_edge(nodes[a], nodes[b]);
+        }
+
+        let is_match = |d: &i32| [3, 5, 9].contains(d);
+
+        assert_eq!(graph.nearest(nodes[0], is_match), Some((nodes[5], 2)));
+        assert_eq!(graph.nearest(nodes[1], is_match), Some((nodes[3], 2)));
+        assert_eq!(graph.nearest(nodes[9], is_match), Some((nodes[9], 0)));
+        assert_eq!(graph.nearest(nodes[0], |&d| d > 100), None);
+    }
+
+    #[test]
+    fn shortest_cycle_through_finds_triangle() {
+        // 0 -> 1 -> 2 -> 0 is the only cycle, 3 hangs off of it
+        let mut graph = VecGraph::new();
+
+        let n0 = graph.add_node(1);
+        let n1 = graph.add_node(2);
+        let n2 = graph.add_node(3);
+        let n3 = graph.add_node(4);
+
+        graph.add_edge(n0, n1);
+        graph.add_edge(n1, n2);
+        graph.add_edge(n2, n0);
+        graph.add_edge(n0, n3);
+        graph.add_edge(n1, n3);
+
+        assert_eq!(
+            graph.shortest_cycle_through(n0, |&d| d),
+            Some((vec![n0, n1, n2, n0], 6))
+        );
+        assert_eq!(
+            graph.shortest_cycle_through(n1, |&d| d),
+            Some((vec![n1, n2, n0, n1], 6))
+        );
+        assert_eq!(graph.shortest_cycle_through(n3, |&d| d), None);
+    }
+
+    #[test]
+    fn shortest_cycle_through_picks_cheapest_cycle() {
+        // Two cycles through 0: 0 -> 1 -> 0 (cost 11) and 0 -> 2 -> 3 -> 0 (cost 3)
+        let mut graph = VecGraph::new();
+
+        let n0 = graph.add_node(1);
+        let n1 = graph.add_node(10);
+        let n2 = graph.add_node(1);
+        let n3 = graph.add_node(1);
+
+        graph.add_edge(n0, n1);
+        graph.add_edge(n1, n0);
+        graph.add_edge(n0, n2);
+        graph.add_edge(n2, n3);
+        graph.add_edge(n3, n0);
+
+        assert_eq!(
+            graph.shortest_cycle_through(n0, |&d| d),
+            Some((vec![n0, n2, n3, n0], 3))
+        );
+    }
+
+    #[test]
+    fn topological_order_with_ties_reproduces_2018_day_7() {
+        let example = "Step C must be finished before step A can begin.
+Step C must be finished before step F can begin.
+Step A must be finished before step B can begin.
+Step A must be finished before step D can begin.
+Step B must be finished before step E can begin.
+Step D must be finished before step E can begin.
+Step F must be finished before step E can begin.";
+
+        let mut graph = VecGraph::new();
+        let mut steps = HashMap::new();
+
+        for line in example.lines() {
+            let words = line.split_whitespace().collect_vec();
+            let (before, after) = (words[1], words[7]);
+
+            let before = *steps.entry(before).or_insert_with(|| graph.add_node(before));
+            let after = *steps.entry(after).or_insert_with(|| graph.add_node(after));
+
+            graph.add_edge(before, after);
+        }
+
+        let order = graph.topological_order_with_ties(|a, b| a.cmp(b)).unwrap();
+        let order = order
+            .iter()
+            .map(|n| *graph.get_data(n).unwrap())
+            .collect::<String>();
+
+        assert_eq!(order, "CABDFE");
+
+        // Reversing the tiebreak changes the order, but it is still topological
+        let order = graph.topological_order_with_ties(|a, b| b.cmp(a)).unwrap();
+        let order = order
+            .iter()
+            .map(|n| *graph.get_data(n).unwrap())
+            .collect::<String>();
+
+        assert_eq!(order, "CFADBE");
+    }
+
+    #[test]
+    fn topological_order_with_ties_detects_cycle() {
+        let mut graph = VecGraph::new();
+
+        let n0 = graph.add_node(0);
+        let n1 = graph.add_node(1);
+        let n2 = graph.add_node(2);
+
+        graph.add_edge(n0, n1);
+        graph.add_edge(n1, n2);
+        graph.add_edge(n2, n1);
+
+        assert_eq!(
+            graph.topological_order_with_ties(|a, b| a.cmp(b)),
+            Err(GraphError::Cycle)
+        );
+    }
+
+    #[test]
+    fn nodes_sorted_by_sorts_descending_and_is_stable() {
+        let mut graph = VecGraph::new();
+
+        let n0 = graph.add_node(('a', 1));
+        let n1 = graph.add_node(('b', 5));
+        let n2 = graph.add_node(('c', 3));
+        let n3 = graph.add_node(('d', 5));
+
+        graph.add_edge(n0, n1);
+
+        let sorted = graph.nodes_sorted_by(|a, b| b.1.cmp(&a.1));
+        assert_eq!(sorted, vec![n1, n3, n2, n0]);
+
+        // The internal order is not changed
+        assert_eq!(graph.iter().copied().collect_vec(), vec![n0, n1, n2, n3]);
+        assert_eq!(graph.get_neighbors(&n0), vec![n1]);
+    }
+
+    #[test]
+    fn count_paths_works_on_lattice() {
+        // A 4x4 lattice, where each node has an edge to the node to its right, and the node below it.
+        // The number of paths from the top left to the bottom right corner is C(6, 3) = 20.
+        let size = 4;
+        let mut graph = VecGraph::new();
+
+        let nodes = (0..size * size).map(|i| graph.add_node(i)).collect_vec();
+        for row in 0..size {
+            for col in 0..size {
+                if col + 1 < size {
+                    graph.add_edge(nodes[row * size + col], nodes[row * size + col + 1]);
+                }
+                if row + 1 < size {
+                    graph.add_edge(nodes[row * size + col], nodes[(row + 1) * size + col]);
+                }
+            }
+        }
+
+        assert_eq!(graph.count_paths(nodes[0], nodes[15]), 20);
+        assert_eq!(graph.count_paths(nodes[5], nodes[15]), 6);
+        assert_eq!(graph.count_paths(nodes[15], nodes[15]), 1);
+        assert_eq!(graph.count_paths(nodes[15], nodes[0]), 0);
+    }
+
+    #[test]
+    fn count_paths_handles_huge_counts() {
+        // A chain of 60 diamonds has 2^60 paths
+        let mut graph = VecGraph::new();
+
+        let mut current = graph.add_node(0);
+        let start = current;
+
+        for _ in 0..60 {
+            let top = graph.add_node(0);
+            let bottom = graph.add_node(0);
+            let next = graph.add_node(0);
+
+            graph.add_edge(current, top);
+            graph.add_edge(current, bottom);
+            graph.add_edge(top, next);
+            graph.add_edge(bottom, next);
+
+            current = next;
+        }
+
+        assert_eq!(graph.count_paths(start, current), 1 << 60);
+    }
+
+    #[test]
+    #[should_panic]
+    fn count_paths_panics_on_cycle() {
+        let mut graph = VecGraph::new();
+
+        let n0 = graph.add_node(0);
+        let n1 = graph.add_node(1);
+
+        graph.add_edge(n0, n1);
+        graph.add_edge(n1, n0);
+
+        graph.count_paths(n0, n1);
+    }
+
+    #[test]
+    fn swap_exchanges_data_but_not_edges() {
+        let mut graph = VecGraph::new();
+
+        let n0 = graph.add_node(String::from("zero"));
+        let n1 = graph.add_node(String::from("one"));
+        let n2 = graph.add_node(String::from("two"));
+
+        graph.add_edge(n0, n1);
+
+        graph.swap(n0, n2);
+
+        assert_eq!(graph.get_data(&n0).unwrap(), "two");
+        assert_eq!(graph.get_data(&n2).unwrap(), "zero");
+        assert_eq!(graph.get_neighbors(&n0), vec![n1]);
+        assert!(graph.get_neighbors(&n2).is_empty());
+
+        graph.swap(n1, n1);
+        assert_eq!(graph.get_data(&n1).unwrap(), "one");
+    }
+
+    #[test]
+    fn greedy_coloring_colors_adjacent_nodes_differently() {
+        // A wheel: a hub connected to a cycle of 5 nodes. An odd cycle needs 3 colors, and the hub a 4th one.
+        let mut graph = VecGraph::new();
+
+        let hub = graph.add_node(0);
+        let rim = (1..=5).map(|i| graph.add_node(i)).collect_vec();
+
+        for i in 0..rim.len() {
+            graph.add_edge(hub, rim[i]);
+            graph.add_edge(rim[(i + 1) % rim.len()], rim[i]);
+        }
+
+        let lonely = graph.add_node(6);
+        graph.add_edge(lonely, lonely);
+
+        let colors = graph.greedy_coloring();
+
+        assert_eq!(colors.len(), 7);
+        for node in graph.iter() {
+            for neighbor in graph.successors(*node).filter(|n| n != node) {
+                assert_ne!(colors[node], colors[&neighbor]);
+            }
+        }
+
+        assert_eq!(colors[&hub], 0); // The hub has the highest degree, so it is colored first
+        assert_eq!(colors[&lonely], 0);
+        assert_eq!(colors.values().max(), Some(&3));
+    }
+
+    #[test]
+    fn neighbor_data_works() {
+        let mut graph = VecGraph::new();
+
+        let n0 = graph.add_node(0);
+        let n1 = graph.add_node(1);
+        let n2 = graph.add_node(2);
+
+        graph.add_edge(n0, n1);
+        graph.add_edge(n0, n2);
+
+        assert_eq!(graph.neighbor_data(n0), vec![&2, &1]);
+        assert!(graph.neighbor_data(n1).is_empty());
+
+        graph.remove_node(n2);
+        assert_eq!(graph.neighbor_data(n0), vec![&1]);
+    }
+
+    #[test]
+    fn successors_size_hint_matches_neighbor_count() {
+        let mut graph = VecGraph::new();
+
+        let nodes = (0..5).map(|i| graph.add_node(i)).collect_vec();
+        for &target in &nodes[1..] {
+            graph.add_edge(nodes[0], target);
+        }
+        graph.add_edge(nodes[1], nodes[2]);
+
+        let mut successors = graph.successors(nodes[0]);
+        assert_eq!(successors.size_hint(), (4, Some(4)));
+        assert_eq!(successors.len(), 4);
+
+        successors.next();
+        assert_eq!(successors.len(), 3);
+        assert_eq!(successors.count(), 3);
+
+        assert_eq!(graph.successors(nodes[1]).len(), 1);
+        assert_eq!(graph.successors(nodes[4]).len(), 0);
+
+        // Removing a node updates the count of its predecessors
+        graph.remove_node(nodes[2]);
+        assert_eq!(graph.successors(nodes[0]).len(), 3);
+        assert_eq!(graph.successors(nodes[1]).len(), 0);
+
+        for node in graph.iter() {
+            assert_eq!(graph.successors(*node).len(), graph.successors(*node).count());
+        }
+    }
+
+    #[test]
+    fn bfs_layers_on_grid() {
+        let grid = Grid::new_from_data(vec![vec!['.'; 3]; 3]);
+        let indices = grid.node_indices.clone().unwrap();
+        let graph = grid.get_underlying_graph();
+
+        let sizes = graph
+            .bfs_layers(indices[0][0])
+            .iter()
+            .map(|layer| layer.len())
+            .collect_vec();
+        assert_eq!(sizes, vec![1, 2, 3, 2, 1]);
+
+        let layers = graph.bfs_layers(indices[1][1]);
+        assert_eq!(layers.iter().map(|l| l.len()).collect_vec(), vec![1, 4, 4]);
+        assert!(layers[2].contains(&indices[2][2]));
+    }
+
+    #[test]
+    fn bfs_layers_skips_unreachable_nodes() {
+        let mut graph = VecGraph::new();
+
+        let a = graph.add_node(());
+        let b = graph.add_node(());
+        let _ = graph.add_node(());
+
+        graph.add_edge(a, b);
+
+        assert_eq!(graph.bfs_layers(a), vec![vec![a], vec![b]]);
+        assert_eq!(graph.bfs_layers(b), vec![vec![b]]);
+    }
+
+    #[test]
+    fn find_loop_containing_returns_undirected_ring() {
+        let mut graph = VecGraph::new();
+
+        let nodes = (0..5).map(|i| graph.add_node(i)).collect_vec();
+        for i in 0..5 {
+            let (a, b) = (nodes[i], nodes[(i + 1) % 5]);
+            graph.add_edge(a, b);
+            graph.add_edge(b, a);
+        }
+
+        let ring = graph.find_loop_containing(nodes[2]).unwrap();
+
+        assert_eq!(ring.len(), 5);
+        assert_eq!(ring[0], nodes[2]);
+        assert_eq!(ring.iter().collect::<HashSet<_>>().len(), 5);
+
+        // Consecutive nodes of the ring are connected
+        for i in 0..5 {
+            let (a, b) = (ring[i], ring[(i + 1) % 5]);
+            assert!(graph.successors(a).any(|n| n == b));
+        }
+    }
+
+    #[test]
+    fn find_loop_containing_rejects_forks_and_dead_ends() {
+        let mut graph = VecGraph::new();
+
+        let a = graph.add_node('a');
+        let b = graph.add_node('b');
+        let c = graph.add_node('c');
+        let d = graph.add_node('d');
+
+        // A path that ends at c
+        graph.add_edge(a, b);
+        graph.add_edge(b, c);
+        assert_eq!(graph.find_loop_containing(a), None);
+
+        // b now forks into c and d
+        graph.add_edge(c, a);
+        graph.add_edge(b, d);
+        assert_eq!(graph.find_loop_containing(a), None);
+
+        // A node without edges is not on a loop
+        let e = graph.add_node('e');
+        assert_eq!(graph.find_loop_containing(e), None);
+    }
+
+    #[test]
+    fn find_loop_containing_ignores_tail_at_start() {
+        // A triangle s - a - b, with a tail s - t, added before and after the triangle
+        for tail_first in [false, true] {
+            let mut graph = VecGraph::new();
+
+            let s = graph.add_node('S');
+            let a = graph.add_node('a');
+            let b = graph.add_node('b');
+            let t = graph.add_node('t');
+
+            let mut edges = vec![(s, a), (a, b), (b, s)];
+            if tail_first {
+                edges.insert(0, (s, t));
+            } else {
+                edges.push((s, t));
+            }
+
+            for (x, y) in edges {
+                graph.add_edge(x, y);
+                graph.add_edge(y, x);
+            }
+
+            let ring = graph.find_loop_containing(s).unwrap();
+            assert_eq!(ring.len(), 3);
+            assert_eq!(ring[0], s);
+            assert!(!ring.contains(&t));
+
+            assert_eq!(graph.find_loop_containing(t), None);
+        }
+    }
+
+    #[test]
+    fn find_loop_containing_returns_two_cycle() {
+        let mut graph = VecGraph::new();
+
+        let a = graph.add_node('a');
+        let b = graph.add_node('b');
+        let c = graph.add_node('c');
+
+        graph.add_edge(a, b);
+        graph.add_edge(b, a);
+
+        assert_eq!(graph.find_loop_containing(a), Some(vec![a, b]));
+        assert_eq!(graph.find_loop_containing(b), Some(vec![b, a]));
+
+        // The edge back to the start has to exist
+        graph.add_edge(c, a);
+        assert_eq!(graph.find_loop_containing(c), None);
+    }
+
+    #[test]
+    fn dijkstra_breaks_ties_by_node_index() {
+        // Two paths with the same cost, with the edges added in both orders
+        for reversed in [false, true] {
+            let mut graph = VecGraph::new();
+
+            let start = graph.add_node(0);
+            let a = graph.add_node(1);
+            let b = graph.add_node(1);
+            let target = graph.add_node(1);
+
+            let mut edges = vec![(start, a), (start, b), (a, target), (b, target)];
+            if reversed {
+                edges.reverse();
+            }
+            for (source, dest) in edges {
+                graph.add_edge(source, dest);
+            }
+
+            assert_eq!(graph.dijkstra_with_cost_ordered(start, target, |&d| d), Some((vec![start, a, target], 2)));
+            assert_eq!(reconstruct_from_tree(&graph.shortest_path_tree(start, |&d| d), start, target), vec![start, a, target]);
+        }
+    }
+
+    #[test]
+    fn all_shortest_paths_finds_both_routes_around_a_wall() {
+        // Two routes of the same length go around the wall, a third one is longer
+        let grid = Grid::new_from_data(vec![
+            vec![1, 1, 1],
+            vec![1, 9, 1],
+            vec![1, 1, 1],
+        ]);
+        let n = grid.node_indices.clone().unwrap();
+        let graph = grid.get_underlying_graph();
+
+        let paths = graph.all_shortest_paths(n[0][0], n[2][2], |&d| d);
+
+        assert_eq!(paths.len(), 2);
+        assert!(paths.contains(&vec![n[0][0], n[0][1], n[0][2], n[1][2], n[2][2]]));
+        assert!(paths.contains(&vec![n[0][0], n[1][0], n[2][0], n[2][1], n[2][2]]));
+
+        let (_, cost) = graph.dijkstra_with_cost(n[0][0], n[2][2], |&d| d).unwrap();
+        assert!(paths.iter().all(|p| p[1..].iter().map(|n| graph.get_data(n).unwrap()).sum::<usize>() == cost));
+    }
+
+    #[test]
+    fn all_shortest_paths_edge_cases() {
+        let mut graph = VecGraph::new();
+
+        let a = graph.add_node(1);
+        let b = graph.add_node(0);
+        let c = graph.add_node(0);
+
+        // b and c form a cycle of cost 0
+        graph.add_edge(a, b);
+        graph.add_edge(b, c);
+        graph.add_edge(c, b);
+
+        assert_eq!(graph.all_shortest_paths(a, a, |&d| d), vec![vec![a]]);
+        assert_eq!(graph.all_shortest_paths(b, a, |&d| d), Vec::<Vec<NodeIndex>>::new());
+        assert_eq!(graph.all_shortest_paths(a, c, |&d| d), vec![vec![a, b, c]]);
+    }
+
+    #[test]
+    fn remove_leaves_keeps_core_cycle() {
+        // A square cycle with a corridor attached to one corner, which itself branches
+        let mut graph = VecGraph::new();
+        let nodes = (0..8).map(|i| graph.add_node(i)).collect_vec();
+
+        for (a, b) in [(0, 1), (1, 2), (2, 3), (3, 0), (2, 4), (4, 5), (5, 6), (5, 7)] {
+            graph.add_edge(nodes[a], nodes[b]);
+            graph.add_edge(nodes[b], nodes[a]);
+        }
+
+        assert_eq!(graph.remove_leaves(), 4);
+        assert_eq!(graph.node_count(), 4);
+        assert!(nodes[..4].iter().all(|n| graph.get_data(n).is_some()));
+        assert!(nodes[4..].iter().all(|n| graph.get_data(n).is_none()));
+        assert_eq!(graph.get_neighbors(&nodes[2]).len(), 2);
+
+        // Nothing left to remove
+        assert_eq!(graph.remove_leaves(), 0);
     }
-}
 
-impl<T> Graph for VecGraph<T> {
-    type DataType = T;
-    type NodeReference = NodeIndex;
-    type EdgeReference = EdgeIndex;
+    #[test]
+    fn remove_leaves_empties_a_tree() {
+        let mut graph = VecGraph::new();
+        let nodes = (0..5).map(|i| graph.add_node(i)).collect_vec();
 
-    fn new() -> Self
-    where
-        Self: Sized,
-    {
-        VecGraph {
-            nodes: Vec::new(),
-            edges: Vec::new(),
+        // A path, with only one direction for each edge
+        for pair in nodes.windows(2) {
+            graph.add_edge(pair[0], pair[1]);
         }
+
+        assert_eq!(graph.remove_leaves(), 5);
+        assert_eq!(graph.node_count(), 0);
     }
 
-    fn add_node(&mut self, data: Self::DataType) -> Self::NodeReference {
-        let index = self.nodes.len();
-        self.nodes.push(NodeData {
-            data,
-            index: NodeIndex(index),
-            first_outgoing_edge: None,
-        });
-        NodeIndex(index)
+    #[test]
+    fn contract_edge_merges_neighbors_and_sums_weights() {
+        // x - a - b - c, and b - d, with every edge in both directions
+        let mut graph: VecGraph<char, u32> = VecGraph::default();
+
+        let x = graph.push_node('x');
+        let a = graph.push_node('a');
+        let b = graph.push_node('b');
+        let c = graph.push_node('c');
+        let d = graph.push_node('d');
+
+        for (from, to, weight) in [(x, a, 1), (a, b, 2), (b, c, 3), (b, d, 4)] {
+            graph.add_weighted_edge(from, to, weight);
+            graph.add_weighted_edge(to, from, weight);
+        }
+
+        graph.contract_edge(a, b);
+
+        let outgoing: HashMap<_, _> = graph.weighted_successors(a).map(|(n, &w)| (n, w)).collect();
+        assert_eq!(outgoing, HashMap::from([(x, 1), (c, 5), (d, 6)]));
+
+        assert_eq!(graph.weighted_successors(c).collect_vec(), vec![(a, &5)]);
+        assert_eq!(graph.weighted_successors(d).collect_vec(), vec![(a, &6)]);
+        assert_eq!(graph.weighted_successors(x).collect_vec(), vec![(a, &1)]);
+        assert!(graph.node_data(&b).is_none());
+        assert_eq!(graph.node_count(), 4);
+
+        // Contracting again keeps summing
+        graph.contract_edge(a, c);
+        assert_eq!(graph.dijkstra_weighted(x, d), Some((vec![x, a, d], 7)));
     }
 
-    fn add_edge(&mut self, source: Self::NodeReference, target: Self::NodeReference) {
-        let edge_index = self.edges.len();
+    #[test]
+    #[should_panic]
+    fn contract_edge_panics_without_edge() {
+        let mut graph: VecGraph<char, u32> = VecGraph::default();
 
-        // TODO: should we return something (E.g. Result) instead of panicking?
-        if self.nodes.len() < target.0 {
-            panic!("Target node not found!");
+        let a = graph.push_node('a');
+        let b = graph.push_node('b');
+        graph.add_weighted_edge(b, a, 1);
+
+        graph.contract_edge(a, b);
+    }
+
+    #[test]
+    fn contract_edges_collapses_long_corridor() {
+        let mut graph: VecGraph<usize, usize> = VecGraph::default();
+
+        let cells = (0..10_000).map(|i| graph.push_node(i)).collect_vec();
+        for pair in cells.windows(2) {
+            graph.add_weighted_edge(pair[0], pair[1], 1);
+            graph.add_weighted_edge(pair[1], pair[0], 1);
         }
 
-        let Some(source_node) = self.nodes.get_mut(source.0) else {
-            panic!("Source node not found.");
-        };
+        // A side branch going into the corridor, that can not be reached from it
+        let side = graph.push_node(10_000);
+        graph.add_weighted_edge(side, cells[5_000], 7);
 
-        self.edges.push(EdgeData {
-            target,
-            next_outgoing_edge: source_node.first_outgoing_edge,
-        });
+        let (first, last) = (cells[0], cells[9_999]);
+        graph.contract_edges(cells[1..9_999].iter().map(|&cell| (first, cell)));
 
-        source_node.first_outgoing_edge = Some(EdgeIndex(edge_index));
-    }
+        assert_eq!(graph.node_count(), 3);
+        assert_eq!(graph.weighted_successors(first).collect_vec(), vec![(last, &9_999)]);
+        assert_eq!(graph.weighted_successors(last).collect_vec(), vec![(first, &9_999)]);
+        assert_eq!(graph.weighted_successors(side).collect_vec(), vec![(first, &5_007)]);
 
-    fn get_data(&self, node: &Self::NodeReference) -> Option<&Self::DataType> {
-        if let Some(node_data) = self.nodes.get(node.0) {
-            Some(&node_data.data)
-        } else {
-            None
+        // The result is the same as contracting the edges one at a time
+        let mut single: VecGraph<usize, usize> = VecGraph::default();
+        let cells = (0..5).map(|i| single.push_node(i)).collect_vec();
+        for pair in cells.windows(2) {
+            single.add_weighted_edge(pair[0], pair[1], 1);
+            single.add_weighted_edge(pair[1], pair[0], 1);
         }
-    }
+        let mut batch = single.clone();
 
-    fn get_data_mut(&mut self, node: &Self::NodeReference) -> Option<&mut Self::DataType> {
-        if let Some(node_data) = self.nodes.get_mut(node.0) {
-            Some(&mut node_data.data)
-        } else {
-            None
+        for &cell in &cells[1..4] {
+            single.contract_edge(cells[0], cell);
         }
-    }
+        batch.contract_edges(cells[1..4].iter().map(|&cell| (cells[0], cell)));
 
-    fn get_neighbors(&self, node: &Self::NodeReference) -> Vec<Self::NodeReference> {
-        self.successors(*node).collect_vec()
+        assert!(single == batch);
     }
 
-    fn find<F>(&self, predicate: F) -> Option<Self::NodeReference>
-    where
-        F: Fn(&Self::DataType) -> bool,
-    {
-        for node in &self.nodes {
-            if predicate(&node.data) {
-                return Some(node.index);
-            }
+    #[test]
+    fn longest_simple_path_works() {
+        let mut graph: VecGraph<char, usize> = VecGraph::default();
+
+        let s = graph.push_node('s');
+        let a = graph.push_node('a');
+        let b = graph.push_node('b');
+        let t = graph.push_node('t');
+        let unreachable = graph.push_node('u');
+
+        for (from, to, weight) in [(s, a, 2), (s, b, 5), (a, b, 1), (a, t, 10), (b, t, 3)] {
+            graph.add_weighted_edge(from, to, weight);
+            graph.add_weighted_edge(to, from, weight);
         }
 
-        None
+        // The simple paths are s-a-t (12), s-b-t (8), s-a-b-t (6), and s-b-a-t (16)
+        assert_eq!(graph.longest_simple_path(s, t, |&w| w), Some(16));
+
+        // Counting edges instead of weights
+        assert_eq!(graph.longest_simple_path(s, t, |_| 1), Some(3));
+
+        assert_eq!(graph.longest_simple_path(s, s, |&w| w), Some(0));
+        assert_eq!(graph.longest_simple_path(s, unreachable, |&w| w), None);
     }
 
-    fn find_nodes<F>(&self, predicate: F) -> Vec<Self::NodeReference>
-    where
-        F: Fn(&Self::DataType) -> bool,
-    {
-        self.nodes
-            .iter()
-            .filter(|node| predicate(&node.data))
-            .map(|node| node.index)
-            .collect()
+    #[test]
+    fn neighbors_ordered_works() {
+        let mut graph = VecGraph::new();
+
+        let nodes = (0..5).map(|i| graph.add_node(i)).collect::<Vec<_>>();
+
+        graph.add_edge(nodes[0], nodes[3]);
+        graph.add_edge(nodes[0], nodes[1]);
+        graph.add_edge(nodes[0], nodes[4]);
+        graph.add_edge(nodes[0], nodes[2]);
+
+        assert_eq!(
+            graph.neighbors_ordered(nodes[0], NeighborOrder::InsertionOrder),
+            vec![nodes[3], nodes[1], nodes[4], nodes[2]]
+        );
+        assert_eq!(
+            graph.neighbors_ordered(nodes[0], NeighborOrder::ReverseInsertion),
+            vec![nodes[2], nodes[4], nodes[1], nodes[3]]
+        );
+        assert_eq!(
+            graph.neighbors_ordered(nodes[0], NeighborOrder::ReverseInsertion),
+            graph.get_neighbors(&nodes[0])
+        );
+        assert_eq!(
+            graph.neighbors_ordered(nodes[0], NeighborOrder::ByIndex),
+            vec![nodes[1], nodes[2], nodes[3], nodes[4]]
+        );
+        assert!(graph
+            .neighbors_ordered(nodes[1], NeighborOrder::InsertionOrder)
+            .is_empty());
     }
 
-    fn to_dot_file<N, S>(&self, node_name_fn: N, node_style_fn: S) -> String
-    where
-        N: Fn(&Self::DataType) -> String,
-        S: Fn(&Self::DataType) -> String,
-    {
-        let mut graphviz = String::from("digraph {\n");
+    #[test]
+    fn dijkstra_costs_saturate_instead_of_wrapping() {
+        let mut graph = VecGraph::new();
 
-        self.nodes.iter().for_each(|node| {
-            let name = node_name_fn(&node.data);
-            let style = node_style_fn(&node.data);
-            graphviz.push_str(format!(" {} [{}]\n", name, style).as_str());
-        });
+        let start = graph.add_node(0);
+        let wall = graph.add_node(usize::MAX);
+        let detour = graph.add_node(2);
+        let beyond = graph.add_node(1);
+
+        graph.add_edge(start, wall);
+        graph.add_edge(wall, beyond);
+        graph.add_edge(start, detour);
+        graph.add_edge(detour, beyond);
+
+        // The wall is expanded last, and `usize::MAX + 1` must not wrap around to 0, which would make it look like the cheapest way to `beyond`
+        let tree = graph.shortest_path_tree(start, |&d| d);
+        assert_eq!(
+            reconstruct_from_tree(&tree, start, beyond),
+            vec![start, detour, beyond]
+        );
+        assert_eq!(
+            graph.all_shortest_paths(start, beyond, |&d| d),
+            vec![vec![start, detour, beyond]]
+        );
+
+        let (path, cost) = search_states(
+            start,
+            |&n| graph.successors(n).collect(),
+            |_| 0,
+            |_, next| *graph.get_data(next).unwrap(),
+            |&n| n == beyond,
+        )
+        .unwrap();
+        assert_eq!(path, vec![start, detour, beyond]);
+        assert_eq!(cost, 3);
+
+        // When the only path leads through the wall, its cost stays at the maximum
+        let mut graph = VecGraph::new();
 
-        graphviz.push('\n');
+        let start = graph.add_node(0);
+        let wall = graph.add_node(usize::MAX);
+        let target = graph.add_node(1);
+
+        graph.add_edge(start, wall);
+        graph.add_edge(wall, target);
+
+        assert_eq!(
+            graph.dijkstra_with_cost(start, target, |&d| d),
+            Some((vec![start, wall, target], usize::MAX))
+        );
+        assert_eq!(
+            search_states(
+                start,
+                |&n| graph.successors(n).collect(),
+                |_| 1,
+                |_, next| *graph.get_data(next).unwrap(),
+                |&n| n == target,
+            ),
+            Some((vec![start, wall, target], usize::MAX))
+        );
+    }
 
-        #[allow(clippy::redundant_closure)]
-        self.nodes.iter().for_each(|node| {
-            let neighbors = self
-                .get_neighbors(&node.index)
-                .iter()
-                .map(|n| self.get_data(n).unwrap())
-                .map(|d| node_name_fn(d))
-                .collect_vec().join(" ");
+    #[test]
+    fn iter_data_works() {
+        let mut graph = VecGraph::new();
 
-            let name = node_name_fn(&node.data);
+        let n0 = graph.add_node(10);
+        let n1 = graph.add_node(20);
+        let n2 = graph.add_node(30);
 
-            graphviz.push_str(format!(" {} -> {{ {} }}\n", name, neighbors).as_str());
+        assert_eq!(
+            graph.iter_data().collect_vec(),
+            vec![(n0, &10), (n1, &20), (n2, &30)]
+        );
+        assert!(graph
+            .iter_data()
+            .map(|(node, _)| node)
+            .eq(graph.iter().copied()));
 
-        });
+        graph.remove_node(n1);
 
-        graphviz.push('}');
-        graphviz
+        assert_eq!(graph.iter_data().collect_vec(), vec![(n0, &10), (n2, &30)]);
     }
-}
 
-impl<'a, T> Iterator for GraphIterator<'a, VecGraph<T>> {
-    type Item = &'a <VecGraph<T> as Graph>::NodeReference;
+    #[test]
+    fn nodes_at_cost_works() {
+        let mut graph = VecGraph::new();
 
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.index < self.graph.nodes.len() {
-            self.index += 1;
-            Some(&self.graph.nodes[self.index - 1].index)
-        } else {
-            None
-        }
-    }
-}
+        let start = graph.add_node(0);
+        let a = graph.add_node(2);
+        let b = graph.add_node(1);
+        let c = graph.add_node(4);
+        let d = graph.add_node(1);
 
-impl<T> Iterator for GraphIntoIterator<VecGraph<T>> {
-    type Item = <VecGraph<T> as Graph>::NodeReference;
+        for (source, target) in [(start, a), (start, b), (b, c), (b, d), (d, a), (a, c)] {
+            graph.add_edge(source, target);
+            graph.add_edge(target, source);
+        }
 
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.graph.nodes.is_empty() {
-            return None;
+        // `a` costs 2 directly and through `b` and `d`, and `d` costs 2 through `b`
+        assert_eq!(graph.nodes_at_cost(start, |&v| v, 2), vec![a, d]);
+        assert_eq!(graph.nodes_at_cost(start, |&v| v, 0), vec![start]);
+        assert_eq!(graph.nodes_at_cost(start, |&v| v, 1), vec![b]);
+        assert_eq!(graph.nodes_at_cost(start, |&v| v, 5), vec![c]);
+        assert!(graph.nodes_at_cost(start, |&v| v, 3).is_empty());
+
+        // With a cost of 1 for every node, this is a layer of a breadth-first search
+        let layers = graph.bfs_layers(start);
+        for (depth, layer) in layers.iter().enumerate() {
+            let mut layer = layer.clone();
+            layer.sort();
+            assert_eq!(graph.nodes_at_cost(start, |_| 1, depth), layer);
         }
+    }
 
-        Some(self.graph.nodes.remove(0).index)
+    #[test]
+    fn append_keeps_edges_of_both_graphs() {
+        let mut graph = VecGraph::new();
+        let a0 = graph.add_node("a0");
+        let a1 = graph.add_node("a1");
+        graph.add_edge(a0, a1);
+        graph.add_edge(a1, a0);
+
+        let mut other = VecGraph::new();
+        let b0 = other.add_node("b0");
+        let b1 = other.add_node("b1");
+        let b2 = other.add_node("b2");
+        other.add_edge(b0, b1);
+        other.add_edge(b0, b2);
+        other.add_edge(b2, b0);
+
+        let moved = graph.append(other);
+
+        assert_eq!(moved, vec![NodeIndex(2), NodeIndex(3), NodeIndex(4)]);
+        assert_eq!(graph.node_count(), 5);
+        assert_eq!(
+            moved.iter().map(|n| *graph.get_data(n).unwrap()).collect_vec(),
+            vec!["b0", "b1", "b2"]
+        );
+
+        // The edges of both graphs are kept, in the same order
+        assert_eq!(graph.get_neighbors(&a0), vec![a1]);
+        assert_eq!(graph.get_neighbors(&a1), vec![a0]);
+        assert_eq!(graph.get_neighbors(&moved[b0.0]), vec![moved[b2.0], moved[b1.0]]);
+        assert!(graph.get_neighbors(&moved[b1.0]).is_empty());
+        assert_eq!(graph.get_neighbors(&moved[b2.0]), vec![moved[b0.0]]);
+
+        // The parts can be stitched together
+        graph.add_edge(a1, moved[b0.0]);
+        let (path, _) = graph.dijkstra_with_cost(a0, moved[b1.0], |_| 1).unwrap();
+        assert_eq!(path, vec![a0, a1, moved[b0.0], moved[b1.0]]);
+
+        // Removed nodes of the appended graph stay removed
+        let mut other = VecGraph::new();
+        let c0 = other.add_node("c0");
+        let c1 = other.add_node("c1");
+        other.add_edge(c1, c0);
+        other.remove_node(c0);
+
+        let moved = graph.append(other);
+        assert!(graph.get_data(&moved[c0.0]).is_none());
+        assert!(graph.get_neighbors(&moved[c1.0]).is_empty());
+        assert_eq!(graph.node_count(), 6);
     }
-}
 
-impl<T> IntoIterator for VecGraph<T> {
-    type Item = <Self as Graph>::NodeReference;
+    #[test]
+    fn transpose_reverses_edges() {
+        let mut graph: VecGraph<char, usize> = VecGraph::default();
 
-    type IntoIter = GraphIntoIterator<VecGraph<T>>;
+        let a = graph.push_node('a');
+        let b = graph.push_node('b');
+        let c = graph.push_node('c');
 
-    fn into_iter(self) -> Self::IntoIter {
-        GraphIntoIterator { graph: self }
-    }
-}
+        graph.add_weighted_edge(a, b, 1);
+        graph.add_weighted_edge(a, c, 2);
+        graph.add_weighted_edge(b, c, 3);
 
-impl<T> VecGraph<T> {
-    /// Return a [`Successors`] that can be used to iterate over the nodes that are connected to 'source'.
-    ///
-    /// # Arguments
-    ///  * 'source' - The source node.
-    ///
-    /// # Panics
-    ///
-    /// Panics if 'source' contains an index that does not correspond to an existing node.
-    pub fn successors(&self, source: NodeIndex) -> Successors<T> {
-        if let Some(n) = self.nodes.get(source.0) {
-            Successors {
-                graph: self,
-                current_edge_index: n.first_outgoing_edge,
-            }
-        } else {
-            panic!("Source not not found!");
-        }
-    }
+        let transposed = graph.transpose();
 
-    /// Returns a [`GraphIterator<VecGraph<T>>`] which can be used to iterate over the node references in this graph.
-    ///
-    /// # Example:
-    ///
-    /// ```
-    /// use aoc_helper::graph::{Graph, vec_graph::VecGraph};
-    /// let mut graph: VecGraph<usize> = VecGraph::new();
-    ///
-    /// graph.add_node(1);
-    /// graph.add_node(2);
-    /// graph.add_node(3);
-    /// graph.add_node(4);
-    ///
-    /// let mut graph_data = Vec::new();
-    /// for node in graph.iter() {
-    ///     graph_data.push(*graph.get_data(node).unwrap());
-    /// }
-    ///
-    /// assert_eq!(graph_data.len(), 4);
-    /// assert_eq!(&graph_data, &[1, 2, 3, 4]);
-    /// ```
-    pub fn iter(&self) -> GraphIterator<VecGraph<T>> {
-        GraphIterator {
-            graph: self,
-            index: 0,
-        }
+        assert!(transposed.weighted_successors(a).next().is_none());
+        assert_eq!(transposed.weighted_successors(b).collect_vec(), vec![(a, &1)]);
+        assert_eq!(
+            transposed.weighted_successors(c).collect_vec(),
+            vec![(b, &3), (a, &2)]
+        );
+        assert_eq!(transposed.node_data(&c), Some(&'c'));
+
+        // Transposing twice gives back the original edges, in the same order
+        assert!(transposed.transpose() == graph);
     }
-}
 
-impl<'graph, T> Iterator for Successors<'graph, T> {
-    type Item = NodeIndex;
+    #[test]
+    fn dijkstra_bidirectional_matches_dijkstra() {
+        // A small linear congruential generator, so the graphs are random, but the same in every run
+        let mut seed = 12345u64;
+        let mut next_random = |max: usize| {
+            seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            (seed >> 33) as usize % max
+        };
 
-    fn next(&mut self) -> Option<Self::Item> {
-        match &self.current_edge_index {
-            Some(edge_index) => {
-                if let Some(edge) = self.graph.edges.get(edge_index.0) {
-                    self.current_edge_index = edge.next_outgoing_edge;
-                    Some(edge.target)
-                } else {
-                    panic!("Edge not found!");
+        for size in [2, 5, 10, 30] {
+            let mut graph = VecGraph::new();
+            let nodes = (0..size)
+                .map(|_| graph.add_node(next_random(10)))
+                .collect_vec();
+
+            for _ in 0..size * 2 {
+                graph.add_edge(nodes[next_random(size)], nodes[next_random(size)]);
+            }
+
+            for &start in &nodes {
+                for &target in &nodes {
+                    let expected = graph.dijkstra_with_cost(start, target, |&d| d);
+                    let actual = graph.dijkstra_bidirectional(start, target, |&d| d);
+
+                    assert_eq!(
+                        expected.as_ref().map(|(_, cost)| *cost),
+                        actual.as_ref().map(|(_, cost)| *cost)
+                    );
+
+                    if let Some((path, cost)) = actual {
+                        assert_eq!(path.first(), Some(&start));
+                        assert_eq!(path.last(), Some(&target));
+                        assert!(path
+                            .windows(2)
+                            .all(|pair| graph.get_neighbors(&pair[0]).contains(&pair[1])));
+                        assert_eq!(
+                            path[1..]
+                                .iter()
+                                .map(|n| graph.get_data(n).unwrap())
+                                .sum::<usize>(),
+                            cost
+                        );
+                    }
                 }
             }
-            None => None,
         }
     }
-}
-
-pub struct Successors<'graph, T> {
-    graph: &'graph VecGraph<T>,
-    current_edge_index: Option<EdgeIndex>,
-}
-
-#[derive(Clone)]
-struct NodeData<T> {
-    data: T,
-    index: NodeIndex,
-    first_outgoing_edge: Option<EdgeIndex>,
-}
 
-struct EdgeData {
-    target: NodeIndex,
-    next_outgoing_edge: Option<EdgeIndex>,
-}
+    #[test]
+    fn dijkstra_bidirectional_on_grid() {
+        let grid = Grid::new_from_data(vec![
+            vec![1, 1, 6, 3, 7, 5, 1, 7, 4, 2],
+            vec![1, 3, 8, 1, 3, 7, 3, 6, 7, 2],
+            vec![2, 1, 3, 6, 5, 1, 1, 3, 2, 8],
+            vec![3, 6, 9, 4, 9, 3, 1, 5, 6, 9],
+            vec![7, 4, 6, 3, 4, 1, 7, 1, 1, 1],
+            vec![1, 3, 1, 9, 1, 2, 8, 1, 3, 7],
+            vec![1, 3, 5, 9, 9, 1, 2, 4, 2, 1],
+            vec![3, 1, 2, 5, 4, 2, 1, 6, 3, 9],
+            vec![1, 2, 9, 3, 1, 3, 8, 5, 2, 1],
+            vec![2, 3, 1, 1, 9, 4, 4, 5, 8, 1],
+        ]);
+        let graph = grid.get_underlying_graph();
+        let start = grid.first_index().unwrap();
+        let target = grid.last_index().unwrap();
+
+        let (_, cost) = graph.dijkstra_bidirectional(start, target, |&d| d).unwrap();
+
+        assert_eq!(cost, 40);
+        assert_eq!(
+            graph.dijkstra_bidirectional(target, target, |&d| d),
+            Some((vec![target], 0))
+        );
+    }
 
-#[cfg(test)]
-pub mod test {
-    use std::collections::HashMap;
+    #[test]
+    fn retain_nodes_removes_nodes_and_edges() {
+        let mut graph = VecGraph::new();
 
-    use regex::Regex;
+        let nodes = (0..6).map(|n| graph.add_node(n)).collect_vec();
 
-    use crate::{direction::Direction, geometry::point::Point2D};
+        for &source in &nodes {
+            for &target in &nodes {
+                if source != target {
+                    graph.add_edge(source, target);
+                }
+            }
+        }
 
-    use super::*;
+        graph.retain_nodes(|n| n % 2 == 0);
 
-    #[test]
-    fn graph_creation_works() {
-        let mut graph: VecGraph<usize> = VecGraph::new();
+        assert!(graph.find_nodes(|n| n % 2 == 1).is_empty());
+        assert_eq!(graph.node_count(), 3);
 
-        let n0 = graph.add_node(1);
-        let n1 = graph.add_node(2);
-        let n2 = graph.add_node(3);
-        let n3 = graph.add_node(4);
+        for node in [nodes[0], nodes[2], nodes[4]] {
+            let neighbors = graph.get_neighbors(&node);
 
-        graph.add_edge(n0, n1);
-        graph.add_edge(n1, n2);
-        graph.add_edge(n0, n3);
-        graph.add_edge(n3, n2);
+            assert_eq!(neighbors.len(), 2);
+            assert!(neighbors
+                .iter()
+                .all(|n| graph.get_data(n).unwrap() % 2 == 0));
+        }
 
-        let s1 = graph.successors(n0).collect::<Vec<_>>();
-        assert_eq!(&s1, &[n3, n1]);
+        for node in [nodes[1], nodes[3], nodes[5]] {
+            assert!(!graph.contains_node(node));
+            assert!(graph.get_data(&node).is_none());
+            assert!(graph.get_neighbors(&node).is_empty());
+        }
 
-        graph.add_edge(n3, n0);
-        graph.add_edge(n3, n3);
+        // Surviving indices are still valid
+        assert_eq!(*graph.get_data(&nodes[4]).unwrap(), 4);
 
-        let s2 = graph.successors(n3).collect::<Vec<_>>();
-        assert_eq!(&s2, &[n3, n0, n2]);
+        let values = graph.iter().map(|n| *graph.get_data(n).unwrap()).collect_vec();
+        assert_eq!(&values, &[0, 2, 4]);
     }
 
     #[test]
@@ -487,6 +3205,34 @@ pub mod test {
         assert_eq!(&path, &[n0, n4]);
     }
 
+    #[test]
+    fn shortest_path_tree_can_reconstruct_multiple_targets() {
+        let mut graph: VecGraph<usize> = VecGraph::new();
+
+        let n0 = graph.add_node(0);
+        let n1 = graph.add_node(1);
+        let n2 = graph.add_node(1);
+        let n3 = graph.add_node(1);
+        let n4 = graph.add_node(1);
+        let expensive = graph.add_node(10);
+        let unreachable = graph.add_node(1);
+
+        graph.add_edge(n0, n1);
+        graph.add_edge(n0, expensive);
+        graph.add_edge(expensive, n2);
+        graph.add_edge(n1, n3);
+        graph.add_edge(n3, n2);
+        graph.add_edge(n2, n4);
+        graph.add_edge(n3, n4);
+
+        let tree = graph.shortest_path_tree(n0, |&v| v);
+
+        assert_eq!(reconstruct_from_tree(&tree, n0, n2), vec![n0, n1, n3, n2]);
+        assert_eq!(reconstruct_from_tree(&tree, n0, n4), vec![n0, n1, n3, n4]);
+        assert_eq!(reconstruct_from_tree(&tree, n0, n0), vec![n0]);
+        assert!(reconstruct_from_tree(&tree, n0, unreachable).is_empty());
+    }
+
     #[test]
     fn dijkstra_search_with_closure_works() {
         // Example data from AoC 2023 Day 17
@@ -715,21 +3461,7 @@ pub mod test {
 
     /// Check if moving in 'dir' would move us out of bounds. If yes, return None, otherwise calculate and return the new position
     fn apply_direction(pos: &Point2D<usize>, dir: &RelativeDirection) -> Option<Point2D<usize>> {
-        let (row_offset, col_offset) = RelativeDirection::get_offset(dir);
-
-        if col_offset == -1 && pos.x == 0 {
-            return None;
-        }
-
-        if row_offset == -1 && pos.y == 0 {
-            return None;
-        }
-
-        // Cast to isize so that we can add a possibly negative number
-        let new_x = ((pos.x as isize) + (col_offset as isize)) as usize;
-        let new_y = ((pos.y as isize) + (row_offset as isize)) as usize;
-
-        Some(Point2D { x: new_x, y: new_y })
+        pos.checked_add_offset(dir.get_offset())
     }
 
     fn parse_values(example: &str) -> Vec<Vec<usize>> {
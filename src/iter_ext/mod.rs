@@ -1,3 +1,5 @@
+use std::{collections::{HashMap, HashSet}, hash::Hash};
+
 pub trait IterExt : Iterator {
     /// Return the count of elements in self that satisfy the predicate.
     /// 
@@ -46,6 +48,46 @@ pub trait IterExt : Iterator {
     }
 
 
+    /// Convenience function to collect self into a HashSet, keeping only the unique elements.
+    /// 
+    /// # Example:
+    /// 
+    /// ```
+    /// use aoc_helper::iter_ext::IterExt;
+    /// 
+    /// let set = [1, 1, 2, 3, 3].into_iter().collect_set();
+    /// assert_eq!(set.len(), 3);
+    /// assert!(set.contains(&2));
+    /// ```
+    fn collect_set(self) -> HashSet<Self::Item>
+    where
+        Self: Sized,
+        Self::Item: Eq + Hash
+    {
+        self.collect()
+    }
+
+
+    /// Convenience function to collect an iterator of `(key, value)` pairs into a HashMap.
+    /// If a key appears more than once, the last value is kept.
+    /// 
+    /// # Example:
+    /// 
+    /// ```
+    /// use aoc_helper::iter_ext::IterExt;
+    /// 
+    /// let map = ["a", "b", "c"].into_iter().enumerate().map(|(i, s)| (s, i)).collect_map();
+    /// assert_eq!(map["b"], 1);
+    /// ```
+    fn collect_map<K, V>(self) -> HashMap<K, V>
+    where
+        Self: Sized + Iterator<Item = (K, V)>,
+        K: Eq + Hash
+    {
+        self.collect()
+    }
+
+
     /// Convenience function to map and collect an iteratior.
     /// 
     /// # Examples:
@@ -98,6 +140,27 @@ mod test_iter_ext {
         assert_eq!(vec![1,2,3,4], vec);
     }
 
+    #[test]
+    fn collect_set_works() {
+        let set = [1, 1, 2, 3, 3].into_iter().collect_set();
+        assert_eq!(set.len(), 3);
+        assert!([1, 2, 3].iter().all(|n| set.contains(n)));
+
+        let empty = Vec::<char>::new().into_iter().collect_set();
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn collect_map_works() {
+        let map = [('a', 1), ('b', 2), ('c', 3)].into_iter().collect_map();
+        assert_eq!(map.len(), 3);
+        assert_eq!(map[&'b'], 2);
+        assert_eq!(map.get(&'d'), None);
+
+        let last_wins = [(1, "first"), (1, "second")].into_iter().collect_map();
+        assert_eq!(last_wins[&1], "second");
+    }
+
     #[test]
     fn map_collect_vec_works() {
         let double = [1, 2, 3, 4].iter().map_collect_vec(|&v| v * 2);
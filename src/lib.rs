@@ -2,4 +2,10 @@ pub mod geometry;
 pub mod iter_ext;
 pub mod puzzle_input;
 pub mod direction;
-pub mod graph;
\ No newline at end of file
+pub mod graph;
+pub mod parse;
+pub mod collections;
+pub mod range_ext;
+pub mod math;
+pub mod search;
+pub mod vm;
\ No newline at end of file
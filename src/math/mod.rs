@@ -0,0 +1,271 @@
+/// Sort `intervals` and merge the ones that overlap into a minimal set of disjoint closed intervals, ordered by their start.
+/// Each interval is a `(start, end)` pair, where both bounds are included.
+///
+/// Because the intervals contain integers, intervals that touch without overlapping (e.g. `(1, 3)` and `(4, 6)`) are merged as well, since together they cover every value from `1` to `6`.
+///
+/// # Arguments
+///
+/// * `intervals` - The intervals to merge. They are sorted in place.
+///
+/// # Panics
+///
+/// Panics if an interval has a start that is greater than its end.
+///
+/// # Example
+///
+/// ```
+/// use aoc_helper::math::merge_intervals;
+///
+/// let mut intervals = vec![(5, 8), (1, 3), (2, 4), (10, 12)];
+///
+/// assert_eq!(merge_intervals(&mut intervals), vec![(1, 8), (10, 12)]);
+/// ```
+pub fn merge_intervals(intervals: &mut [(i64, i64)]) -> Vec<(i64, i64)> {
+    assert!(
+        intervals.iter().all(|(start, end)| start <= end),
+        "The start of an interval must not be greater than its end."
+    );
+
+    intervals.sort_unstable();
+
+    let mut merged: Vec<(i64, i64)> = Vec::new();
+
+    for &(start, end) in intervals.iter() {
+        match merged.last_mut() {
+            Some((_, last_end)) if start <= last_end.saturating_add(1) => {
+                *last_end = (*last_end).max(end);
+            }
+            _ => merged.push((start, end)),
+        }
+    }
+
+    merged
+}
+
+/// Round `value` to the given number of decimal places, e.g. to compare a calculated area or perimeter with an expected value.
+/// Halfway cases are rounded away from zero.
+///
+/// # Arguments
+///
+/// * `value` - The number to round.
+/// * `decimals` - The number of decimal places to keep.
+///
+/// # Example
+///
+/// ```
+/// use aoc_helper::math::round_to;
+///
+/// assert_eq!(round_to(2.0_f64.sqrt(), 3), 1.414);
+/// ```
+pub fn round_to(value: f64, decimals: u32) -> f64 {
+    let factor = 10_f64.powi(decimals as i32);
+
+    (value * factor).round() / factor
+}
+
+/// Find the first step where several independent cycles all align (e.g. the ghosts of AoC 2023 Day 8).
+/// Each cycle is an `(offset, period)` pair, and holds at the steps `offset`, `offset + period`, `offset + 2 * period`, and so on.
+/// If every offset is a multiple of its period, the answer is found directly from the least common multiple of the periods. Otherwise, the cycles are combined with the Chinese remainder theorem, which also works when the periods are not coprime.
+/// Returns [`None`] if the cycles never align. An empty slice of cycles aligns at step 0.
+///
+/// # Arguments
+///
+/// * `cycles` - The `(offset, period)` pairs of the cycles.
+///
+/// # Panics
+///
+/// Panics if a period is 0, or if the result does not fit in a [`u64`].
+///
+/// # Example
+///
+/// ```
+/// use aoc_helper::math::combine_cycles;
+///
+/// assert_eq!(combine_cycles(&[(4, 4), (6, 6)]), Some(12));
+/// assert_eq!(combine_cycles(&[(2, 3), (3, 5), (2, 7)]), Some(23));
+/// assert_eq!(combine_cycles(&[(0, 2), (1, 4)]), None);
+/// ```
+pub fn combine_cycles(cycles: &[(u64, u64)]) -> Option<u64> {
+    assert!(
+        cycles.iter().all(|&(_, period)| period > 0),
+        "The period of a cycle must be greater than 0."
+    );
+
+    let first_valid = cycles.iter().map(|&(offset, _)| offset).max().unwrap_or(0) as i128;
+
+    // The cycles hold at every common multiple of the periods
+    if cycles.iter().all(|&(offset, period)| offset % period == 0) {
+        let lcm = cycles.iter().fold(1, |acc, &(_, period)| {
+            acc / gcd(acc, period as i128) * period as i128
+        });
+        let step = (first_valid + lcm - 1) / lcm * lcm;
+
+        return Some(u64::try_from(step).expect("The result does not fit in a u64."));
+    }
+
+    // Combine the congruences step = remainder (mod modulus) one by one
+    let (mut remainder, mut modulus) = (0_i128, 1_i128);
+
+    for &(offset, period) in cycles {
+        let (offset, period) = (offset as i128 % period as i128, period as i128);
+        let divisor = gcd(modulus, period);
+
+        if (offset - remainder) % divisor != 0 {
+            return None;
+        }
+
+        // Solve remainder + modulus * k = offset (mod period) for k
+        let reduced_period = period / divisor;
+        let k = (offset - remainder) / divisor % reduced_period
+            * mod_inverse(modulus / divisor % reduced_period, reduced_period)
+            % reduced_period;
+
+        remainder = (remainder + modulus * k).rem_euclid(modulus * reduced_period);
+        modulus *= reduced_period;
+    }
+
+    // The smallest solution can come before some of the cycles start
+    let step = if remainder < first_valid {
+        remainder + (first_valid - remainder + modulus - 1) / modulus * modulus
+    } else {
+        remainder
+    };
+
+    Some(u64::try_from(step).expect("The result does not fit in a u64."))
+}
+
+/// Return the greatest common divisor of `a` and `b`.
+fn gcd(a: i128, b: i128) -> i128 {
+    if b == 0 {
+        a.abs()
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+/// Return the inverse of `a` modulo `modulus`, i.e. the `x` in `0..modulus` for which `a * x = 1 (mod modulus)`. `a` and `modulus` must be coprime.
+fn mod_inverse(a: i128, modulus: i128) -> i128 {
+    // Extended Euclidean algorithm
+    let (mut old_r, mut r) = (a.rem_euclid(modulus), modulus);
+    let (mut old_s, mut s) = (1_i128, 0_i128);
+
+    while r != 0 {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_s, s) = (s, old_s - quotient * s);
+    }
+
+    old_s.rem_euclid(modulus)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn merge_intervals_works() {
+        let mut intervals = vec![(12, 12), (2, 8), (-2, 2), (16, 24), (14, 18)];
+
+        assert_eq!(
+            merge_intervals(&mut intervals),
+            vec![(-2, 8), (12, 12), (14, 24)]
+        );
+    }
+
+    #[test]
+    fn merge_intervals_merges_nested_and_touching_intervals() {
+        let mut intervals = vec![(1, 10), (2, 3), (4, 6), (11, 11), (13, 15)];
+
+        assert_eq!(merge_intervals(&mut intervals), vec![(1, 11), (13, 15)]);
+
+        let mut touching = vec![(4, 6), (1, 3)];
+        assert_eq!(merge_intervals(&mut touching), vec![(1, 6)]);
+    }
+
+    #[test]
+    fn merge_intervals_handles_empty_input() {
+        assert!(merge_intervals(&mut []).is_empty());
+    }
+
+    #[test]
+    fn combine_cycles_solves_2023_day_8() {
+        let input = "LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22B, XXX)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+XXX = (XXX, XXX)";
+
+        let (instructions, network) = input.split_once("\n\n").unwrap();
+        let network: std::collections::HashMap<&str, (&str, &str)> = network
+            .lines()
+            .map(|line| (&line[0..3], (&line[7..10], &line[12..15])))
+            .collect();
+
+        // In this example, every ghost returns to a Z node with the same period as it took to reach the first one
+        let cycles = network
+            .keys()
+            .filter(|node| node.ends_with('A'))
+            .map(|&start| {
+                let mut node = start;
+                let mut steps = 0;
+
+                for direction in instructions.chars().cycle() {
+                    node = if direction == 'L' {
+                        network[node].0
+                    } else {
+                        network[node].1
+                    };
+                    steps += 1;
+
+                    if node.ends_with('Z') {
+                        break;
+                    }
+                }
+
+                (steps, steps)
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(combine_cycles(&cycles), Some(6));
+    }
+
+    #[test]
+    fn combine_cycles_with_offsets() {
+        // Coprime periods
+        assert_eq!(combine_cycles(&[(2, 3), (3, 5), (2, 7)]), Some(23));
+
+        // Periods that share a factor
+        assert_eq!(combine_cycles(&[(1, 4), (3, 6)]), Some(9));
+        assert_eq!(combine_cycles(&[(0, 4), (1, 6)]), None);
+
+        // The answer can not come before the offset of a cycle
+        assert_eq!(combine_cycles(&[(10, 3)]), Some(10));
+        assert_eq!(combine_cycles(&[(1, 2), (13, 4)]), Some(13));
+        assert_eq!(combine_cycles(&[(0, 2), (6, 3)]), Some(6));
+
+        assert_eq!(combine_cycles(&[]), Some(0));
+        assert_eq!(combine_cycles(&[(0, 5), (0, 7)]), Some(0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn combine_cycles_panics_on_zero_period() {
+        combine_cycles(&[(1, 0)]);
+    }
+
+    #[test]
+    fn round_to_rounds_up_and_down() {
+        assert_eq!(round_to(4.82842, 3), 4.828);
+        assert_eq!(round_to(611.8034, 3), 611.803);
+        assert_eq!(round_to(611.8036, 3), 611.804);
+        assert_eq!(round_to(1.25, 1), 1.3);
+        assert_eq!(round_to(-1.25, 1), -1.3);
+        assert_eq!(round_to(1.6, 0), 2.0);
+        assert_eq!(round_to(1.4, 0), 1.0);
+    }
+}
@@ -0,0 +1,182 @@
+use crate::vm::{drive, ControlFlow};
+
+/// A generic assembly-style instruction: an operation name followed by its arguments, e.g. `cpy 41 a`.
+/// Useful when a puzzle's instructions are simple enough that a dedicated enum is not worth writing.
+///
+/// # Example:
+///
+/// ```
+/// use aoc_helper::parse::instruction::Instruction;
+///
+/// let instruction = Instruction::parse("jnz a -2");
+///
+/// assert_eq!(instruction.op, "jnz");
+/// assert_eq!(instruction.arg(0), Some("a"));
+/// assert_eq!(instruction.int_arg(1), Some(-2));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Instruction {
+    /// The name of the operation.
+    pub op: String,
+    /// The arguments of the operation, in order.
+    pub args: Vec<String>,
+}
+
+impl Instruction {
+    /// Parse an instruction from a line, where the operation and the arguments are separated by whitespace.
+    /// Commas after arguments are ignored, so `jio a, +19` is parsed as `jio` with the arguments `a` and `+19`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the line is empty.
+    pub fn parse(line: &str) -> Instruction {
+        let mut parts = line.split_whitespace();
+        let op = parts.next().expect("Empty instruction").to_string();
+        let args = parts
+            .map(|arg| arg.trim_end_matches(',').to_string())
+            .collect();
+
+        Instruction { op, args }
+    }
+
+    /// Return the argument at `index`, or [`None`] if the instruction does not have that many arguments.
+    pub fn arg(&self, index: usize) -> Option<&str> {
+        self.args.get(index).map(String::as_str)
+    }
+
+    /// Return the argument at `index` parsed as an integer, or [`None`] if it does not exist or is not an integer (e.g. a register name).
+    pub fn int_arg(&self, index: usize) -> Option<i64> {
+        self.arg(index)?.parse().ok()
+    }
+}
+
+/// Something that can execute a program of instructions of type `I`, one instruction at a time.
+/// Unlike [`Machine`](crate::vm::Machine), the implementor keeps its own state, and decides what each instruction does in [`Executor::execute`].
+///
+/// # Example:
+///
+/// ```
+/// use aoc_helper::{
+///     parse::{instruction::{Executor, Instruction}, parse_instructions},
+///     vm::ControlFlow,
+/// };
+///
+/// struct Accumulator(i64);
+///
+/// impl Executor<Instruction> for Accumulator {
+///     fn execute(&mut self, instruction: &Instruction) -> ControlFlow {
+///         match instruction.op.as_str() {
+///             "add" => self.0 += instruction.int_arg(0).unwrap(),
+///             "jmp" => return ControlFlow::Jump(instruction.int_arg(0).unwrap() as isize),
+///             _ => {}
+///         }
+///         ControlFlow::Next
+///     }
+/// }
+///
+/// let program = parse_instructions("add 5\njmp 2\nadd 100\nadd -1", Instruction::parse);
+/// let mut accumulator = Accumulator(0);
+///
+/// assert_eq!(accumulator.run(&program), 3);
+/// assert_eq!(accumulator.0, 4);
+/// ```
+pub trait Executor<I> {
+    /// Execute a single instruction, and return where the program continues.
+    /// Most instructions return [`ControlFlow::Next`], while jumps return [`ControlFlow::Jump`] with an offset relative to this instruction.
+    fn execute(&mut self, instruction: &I) -> ControlFlow;
+
+    /// Execute `program`, starting at the first instruction, until the instruction pointer leaves the program, or an instruction returns [`ControlFlow::Halt`].
+    /// Returns the number of instructions that were executed. Does not return if the program never terminates.
+    fn run(&mut self, program: &[I]) -> usize {
+        drive(program, &mut 0, |_, instruction| self.execute(instruction))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::parse::parse_instructions;
+
+    use super::*;
+
+    #[test]
+    fn instruction_parse_works() {
+        let instruction = Instruction::parse("jio a, +19");
+
+        assert_eq!(instruction.op, "jio");
+        assert_eq!(instruction.args, vec!["a", "+19"]);
+        assert_eq!(instruction.arg(0), Some("a"));
+        assert_eq!(instruction.int_arg(0), None);
+        assert_eq!(instruction.int_arg(1), Some(19));
+        assert_eq!(instruction.arg(2), None);
+
+        let no_args = Instruction::parse("hlf");
+        assert!(no_args.args.is_empty());
+    }
+
+    #[test]
+    fn executor_runs_2016_day_12() {
+        struct Registers([i64; 4]);
+
+        impl Registers {
+            fn value(&self, arg: &str) -> i64 {
+                arg.parse()
+                    .unwrap_or_else(|_| self.0[(arg.as_bytes()[0] - b'a') as usize])
+            }
+        }
+
+        impl Executor<Instruction> for Registers {
+            fn execute(&mut self, instruction: &Instruction) -> ControlFlow {
+                let register = |i: usize| (instruction.args[i].as_bytes()[0] - b'a') as usize;
+
+                match instruction.op.as_str() {
+                    "cpy" => self.0[register(1)] = self.value(&instruction.args[0]),
+                    "inc" => self.0[register(0)] += 1,
+                    "dec" => self.0[register(0)] -= 1,
+                    "jnz" if self.value(&instruction.args[0]) != 0 => {
+                        return ControlFlow::Jump(self.value(&instruction.args[1]) as isize)
+                    }
+                    _ => {}
+                }
+
+                ControlFlow::Next
+            }
+        }
+
+        let program = parse_instructions(
+            "cpy 41 a
+inc a
+inc a
+dec a
+jnz a 2
+dec a",
+            Instruction::parse,
+        );
+        let mut registers = Registers([0; 4]);
+
+        assert_eq!(registers.run(&program), 5);
+        assert_eq!(registers.0[0], 42);
+    }
+
+    #[test]
+    fn executor_stops_on_halt() {
+        struct Counter(usize);
+
+        impl Executor<Instruction> for Counter {
+            fn execute(&mut self, instruction: &Instruction) -> ControlFlow {
+                self.0 += 1;
+
+                match instruction.op.as_str() {
+                    "hlt" => ControlFlow::Halt,
+                    "jmp" => ControlFlow::Jump(instruction.int_arg(0).unwrap() as isize),
+                    _ => ControlFlow::Next,
+                }
+            }
+        }
+
+        let program = parse_instructions("nop\njmp 2\nnop\nhlt\nnop", Instruction::parse);
+        let mut counter = Counter(0);
+
+        assert_eq!(counter.run(&program), 3);
+        assert_eq!(counter.0, 3);
+    }
+}
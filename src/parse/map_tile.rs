@@ -0,0 +1,53 @@
+/// A cell of a maze-like map, where `#` is a wall, `.` is open ground, and `S` and `E` mark the start and the end (e.g. AoC 2024 Day 16).
+/// Characters that do not have a meaning of their own are kept as [`MapTile::Other`].
+///
+/// # Example:
+///
+/// ```
+/// use aoc_helper::parse::map_tile::MapTile;
+///
+/// assert_eq!(MapTile::Wall, MapTile::from_char('#'));
+/// assert_eq!(MapTile::Other('^'), MapTile::from_char('^'));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MapTile {
+    Wall,
+    Open,
+    Start,
+    End,
+    Other(char),
+}
+
+impl MapTile {
+    /// Return the [`MapTile`] that corresponds to `c`.
+    pub fn from_char(c: char) -> MapTile {
+        match c {
+            '#' => MapTile::Wall,
+            '.' => MapTile::Open,
+            'S' => MapTile::Start,
+            'E' => MapTile::End,
+            other => MapTile::Other(other),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn from_char_works() {
+        let tiles = "#.SEx".chars().map(MapTile::from_char).collect::<Vec<_>>();
+
+        assert_eq!(
+            tiles,
+            vec![
+                MapTile::Wall,
+                MapTile::Open,
+                MapTile::Start,
+                MapTile::End,
+                MapTile::Other('x')
+            ]
+        );
+    }
+}
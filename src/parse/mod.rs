@@ -0,0 +1,238 @@
+use std::{collections::HashMap, ops::RangeInclusive, str::FromStr};
+
+use crate::puzzle_input::PuzzleParseError;
+
+pub mod instruction;
+pub mod map_tile;
+
+/// Parse a block of whitespace separated `key:value` pairs (e.g. a passport from AoC 2020 Day 4).
+/// The pairs can be separated by any whitespace, including newlines. Each pair is split on the first `:`, so the value may contain additional `:` characters.
+/// Tokens that do not contain a `:` are ignored. If a key appears more than once, the last value is kept.
+///
+/// # Arguments
+///
+/// * `block` - A string slice containing the `key:value` pairs.
+///
+/// # Examples
+///
+/// ```
+/// use aoc_helper::parse::parse_key_values;
+///
+/// let passport = parse_key_values("ecl:gry pid:860033327\nbyr:1937 hgt:183cm");
+///
+/// assert_eq!(passport.len(), 4);
+/// assert_eq!(passport["ecl"], "gry");
+/// assert_eq!(passport["hgt"], "183cm");
+/// ```
+pub fn parse_key_values(block: &str) -> HashMap<String, String> {
+    block
+        .split_whitespace()
+        .filter_map(|token| token.split_once(':'))
+        .map(|(key, value)| (key.to_string(), value.to_string()))
+        .collect()
+}
+
+/// Parse a list of inclusive ranges written as `start-end` (e.g. `2-4,6-8` from AoC 2022 Day 4).
+/// The ranges can be separated by commas or whitespace. Negative bounds are supported, e.g. `-5--1`.
+/// Use [`RangeExt`](crate::range_ext::RangeExt) to compare the parsed ranges.
+///
+/// # Arguments
+///
+/// * `s` - A string slice containing the ranges.
+///
+/// # Panics
+///
+/// Panics if a range is not in the `start-end` format, or if a bound is not a valid integer.
+///
+/// # Examples
+///
+/// ```
+/// use aoc_helper::parse::parse_ranges;
+///
+/// let ranges = parse_ranges("2-4,6-8");
+///
+/// assert_eq!(ranges, vec![2..=4, 6..=8]);
+/// ```
+pub fn parse_ranges(s: &str) -> Vec<RangeInclusive<i64>> {
+    s.split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|token| !token.is_empty())
+        .map(|token| {
+            // Skip the first character when looking for the separator, so that a negative start is not mistaken for it
+            let separator = token[1..]
+                .find('-')
+                .map(|i| i + 1)
+                .unwrap_or_else(|| panic!("Invalid range: {token}"));
+
+            let (start, end) = (&token[..separator], &token[separator + 1..]);
+            let parse = |bound: &str| {
+                bound
+                    .parse::<i64>()
+                    .unwrap_or_else(|_| panic!("Invalid range bound in: {token}"))
+            };
+
+            parse(start)..=parse(end)
+        })
+        .collect()
+}
+
+/// Split `s` on `sep`, and parse each trimmed token as a `T` (e.g. the crab positions `16,1,2,0,4` from AoC 2021 Day 7).
+/// Empty tokens are skipped, so an empty input, a trailing newline, a trailing separator or a doubled separator do not cause an error.
+///
+/// # Arguments
+///
+/// * `s` - A string slice containing the separated values.
+/// * `sep` - The character that separates the values.
+///
+/// # Errors
+///
+/// This function will return an error containing the offending token if any of the tokens can not be parsed as a `T`.
+///
+/// # Examples
+///
+/// ```
+/// use aoc_helper::parse::parse_separated;
+///
+/// let positions = parse_separated::<u32>("16,1,2,0,4\n", ',').unwrap();
+///
+/// assert_eq!(positions, vec![16, 1, 2, 0, 4]);
+/// ```
+pub fn parse_separated<T: FromStr>(s: &str, sep: char) -> Result<Vec<T>, PuzzleParseError> {
+    s.split(sep)
+        .map(str::trim)
+        .filter(|token| !token.is_empty())
+        .map(|token| {
+            token
+                .parse()
+                .map_err(|_| PuzzleParseError::new(format!("Invalid value: '{token}'")))
+        })
+        .collect()
+}
+
+/// Parse a program of assembly-style instructions (e.g. `cpy 41 a` from AoC 2016 Day 12), one instruction per line.
+/// Empty lines are skipped, and the remaining lines are trimmed before they are passed to `parse_line`.
+/// [`Instruction::parse`](instruction::Instruction::parse) can be used as `parse_line` if the program does not need its own instruction type.
+///
+/// # Arguments
+///
+/// * `input` - The program, with one instruction per line.
+/// * `parse_line` - A closure that parses a single line into an instruction.
+///
+/// # Examples
+///
+/// ```
+/// use aoc_helper::parse::{instruction::Instruction, parse_instructions};
+///
+/// let program = parse_instructions("cpy 41 a\ninc a", Instruction::parse);
+///
+/// assert_eq!(program.len(), 2);
+/// assert_eq!(program[1].op, "inc");
+/// ```
+pub fn parse_instructions<I, F>(input: &str, parse_line: F) -> Vec<I>
+where
+    F: Fn(&str) -> I,
+{
+    input
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(parse_line)
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use crate::range_ext::RangeExt;
+
+    use super::*;
+
+    #[test]
+    fn parse_key_values_works() {
+        let block = "ecl:gry pid:860033327\nbyr:1937 hgt:183cm";
+
+        let values = parse_key_values(block);
+
+        assert_eq!(values.len(), 4);
+        assert_eq!(values["ecl"], "gry");
+        assert_eq!(values["pid"], "860033327");
+        assert_eq!(values["byr"], "1937");
+        assert_eq!(values["hgt"], "183cm");
+    }
+
+    #[test]
+    fn parse_key_values_splits_on_first_colon() {
+        let values = parse_key_values("time:12:30 invalid");
+
+        assert_eq!(values.len(), 1);
+        assert_eq!(values["time"], "12:30");
+    }
+
+    #[test]
+    fn parse_ranges_works() {
+        let ranges = parse_ranges("2-4,6-8");
+
+        assert_eq!(ranges, vec![2..=4, 6..=8]);
+        assert!(!ranges[0].overlaps(&ranges[1]));
+
+        let ranges = parse_ranges("5-7,7-9");
+
+        assert!(ranges[0].overlaps(&ranges[1]));
+        assert_eq!(ranges[0].intersection(&ranges[1]), Some(7..=7));
+    }
+
+    #[test]
+    fn parse_ranges_handles_negative_bounds_and_whitespace() {
+        let ranges = parse_ranges("-5--1 -2-3\n10-20");
+
+        assert_eq!(ranges, vec![-5..=-1, -2..=3, 10..=20]);
+    }
+
+    #[test]
+    fn parse_instructions_works() {
+        #[derive(Debug, PartialEq)]
+        enum Op {
+            Inc(char),
+            Jnz(char, i64),
+        }
+
+        let program = parse_instructions("inc a\n\n  jnz a -2\n", |line| {
+            let parts = line.split(' ').collect::<Vec<_>>();
+            let register = parts[1].chars().next().unwrap();
+
+            match parts[0] {
+                "inc" => Op::Inc(register),
+                "jnz" => Op::Jnz(register, parts[2].parse().unwrap()),
+                _ => panic!("Unknown instruction: {line}"),
+            }
+        });
+
+        assert_eq!(program, vec![Op::Inc('a'), Op::Jnz('a', -2)]);
+    }
+
+    #[test]
+    fn parse_separated_works() {
+        assert_eq!(parse_separated::<u32>("1,2,3", ','), Ok(vec![1, 2, 3]));
+        assert_eq!(parse_separated::<i64>("3, -1 ", ','), Ok(vec![3, -1]));
+    }
+
+    #[test]
+    fn parse_separated_skips_empty_tokens() {
+        assert_eq!(parse_separated::<u32>("", ','), Ok(vec![]));
+        assert_eq!(parse_separated::<u32>("1,2,3,", ','), Ok(vec![1, 2, 3]));
+        assert_eq!(parse_separated::<u32>("1,,2", ','), Ok(vec![1, 2]));
+        assert_eq!(parse_separated::<u32>("1,2\n", ','), Ok(vec![1, 2]));
+        assert_eq!(parse_separated::<u32>("1 2\n", ' '), Ok(vec![1, 2]));
+    }
+
+    #[test]
+    fn parse_separated_reports_invalid_token() {
+        let err = parse_separated::<u32>("1,x2,3", ',').unwrap_err();
+
+        assert!(err.to_string().contains("x2"));
+    }
+
+    #[test]
+    #[should_panic]
+    fn parse_ranges_panics_on_invalid_range() {
+        parse_ranges("2-4,6");
+    }
+}
@@ -1,5 +1,5 @@
 #![allow(dead_code)]
-use std::{error::Error, fs, str::FromStr, fmt::Display};
+use std::{env, error::Error, fs, path::Path, str::FromStr, fmt::Display};
 
 /// Trait that indicates that the type implementing it is the puzzle input.
 /// Types implementing [`PuzzleInput`] must also implement [`FromStr`].
@@ -75,12 +75,126 @@ where
     read_puzzle_input("input.txt")
 }
 
+/// Same as [`read_puzzle_input`], but 'relative_path' is resolved against the directory of the package being built (the `CARGO_MANIFEST_DIR` that Cargo sets for `cargo run` and `cargo test`), instead of the current working directory.
+/// This way, inputs stored next to a binary's `Cargo.toml` are found even when it is run from the root of a workspace. If `CARGO_MANIFEST_DIR` is not set, the current working directory is used.
+///
+/// # Arguments
+///
+/// * `relative_path` - A string slice that specifies the input file, relative to the package directory.
+///
+/// # Errors
+///
+/// This function will return an error if the resolved path does not point to a valid file, or if the parsing fails.
+///
+/// # Examples
+///
+/// ```
+/// // This will read 'input.txt' from the package directory, wherever cargo was started from
+/// // let puzzle = read_input_rel::<Puzzle>("input.txt").unwrap();
+/// ```
+pub fn read_input_rel<T>(relative_path: &str) -> Result<T, PuzzleParseError>
+where
+    T: PuzzleInput
+{
+    let base = env::var("CARGO_MANIFEST_DIR").unwrap_or_else(|_| String::from("."));
+
+    read_input_rel_to(&base, relative_path)
+}
+
+/// Same as [`read_input_rel`], but 'relative_path' is resolved against 'base' instead of the package directory.
+///
+/// # Arguments
+///
+/// * `base` - A string slice that specifies the directory that 'relative_path' is relative to.
+/// * `relative_path` - A string slice that specifies the input file, relative to 'base'.
+///
+/// # Errors
+///
+/// This function will return an error if the resolved path does not point to a valid file, or if the parsing fails.
+pub fn read_input_rel_to<T>(base: &str, relative_path: &str) -> Result<T, PuzzleParseError>
+where
+    T: PuzzleInput
+{
+    let path = Path::new(base).join(relative_path);
+
+    read_puzzle_input(&path.to_string_lossy())
+}
+
+/// Read a file that consists of two sections separated by a blank line (e.g. the stacks and the moves in AoC 2022 Day 5), and parse each section into its own type using [`FromStr`].
+/// The file is split on the first blank line, and the sections are passed to [`FromStr::from_str`] as they are, without trimming.
+///
+/// # Arguments
+///
+/// * `file_path` - A string slice that specifies the input file to be read.
+///
+/// # Errors
+///
+/// This function will return an error if the file_path argument does not point to a valid file, if the file does not contain a blank line, or if parsing either section fails.
+///
+/// # Examples
+///
+/// ```
+/// // This will read the file 'input.txt', and parse the part before the first blank line as a Header, and the rest as a Body
+/// // let (header, body) = read_two_parts::<Header, Body>("input.txt").unwrap();
+/// ```
+pub fn read_two_parts<A, B>(file_path: &str) -> Result<(A, B), PuzzleParseError>
+where
+    A: FromStr,
+    B: FromStr
+{
+    let input = fs::read_to_string(file_path).map_err(|err| PuzzleParseError {msg: err.to_string()})?;
+
+    let (first, second) = input.split_once("\n\n").ok_or_else(|| PuzzleParseError {msg: String::from("The input does not contain a blank line!")})?;
+
+    let first = A::from_str(first).map_err(|_| PuzzleParseError {msg: String::from("Could not parse the first part!")})?;
+    let second = B::from_str(second).map_err(|_| PuzzleParseError {msg: String::from("Could not parse the second part!")})?;
+
+    Ok((first, second))
+}
+
+/// Same as [`read_puzzle_input`], but for gzip compressed files (e.g. "input.txt.gz"). The file is decompressed before it is parsed.
+/// Requires the `gzip` feature.
+///
+/// # Arguments
+///
+/// * `file_path` - A string slice that specifies the compressed input file to be read.
+///
+/// # Errors
+///
+/// This function will return an error if the file_path argument does not point to a valid gzip file, if its content is not valid UTF-8, or if the parsing fails.
+///
+/// # Examples
+///
+/// ```
+/// // This will read and decompress the file 'input.txt.gz', and attempt to parse its content using Puzzle::from_str
+/// // let puzzle = read_puzzle_input_gz::<Puzzle>("input.txt.gz").unwrap();
+/// ```
+#[cfg(feature = "gzip")]
+pub fn read_puzzle_input_gz<T>(file_path: &str) -> Result<T, PuzzleParseError>
+where
+    T: PuzzleInput
+{
+    use std::io::Read;
+
+    let file = fs::File::open(file_path).map_err(|err| PuzzleParseError {msg: err.to_string()})?;
+
+    let mut input = String::new();
+    flate2::read::GzDecoder::new(file).read_to_string(&mut input).map_err(|err| PuzzleParseError {msg: err.to_string()})?;
+
+    T::from_str(&input).map_err(|_| PuzzleParseError {msg: String::from("Could not parse str!")})
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct PuzzleParseError {
     msg: String
 }
 
+impl PuzzleParseError {
+    pub(crate) fn new(msg: String) -> PuzzleParseError {
+        PuzzleParseError { msg }
+    }
+}
+
 impl Display for PuzzleParseError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.msg)
@@ -100,3 +214,93 @@ impl Error for PuzzleParseError {
         self.source()
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    struct Numbers {
+        values: Vec<usize>
+    }
+
+    impl FromStr for Numbers {
+        type Err = PuzzleParseError;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            let values = s.lines().map(|l| l.parse().map_err(|_| PuzzleParseError {msg: format!("Invalid number: {l}")})).collect::<Result<_, _>>()?;
+            Ok(Numbers { values })
+        }
+    }
+
+    impl PuzzleInput for Numbers {}
+
+    #[test]
+    fn read_input_rel_resolves_against_base() {
+        let base = concat!(env!("CARGO_MANIFEST_DIR"), "/test_data");
+
+        let numbers = read_input_rel_to::<Numbers>(base, "numbers.txt").unwrap();
+        assert_eq!(vec![1, 2, 3], numbers.values);
+
+        assert!(read_input_rel_to::<Numbers>(env!("CARGO_MANIFEST_DIR"), "numbers.txt").is_err());
+    }
+
+    #[test]
+    fn read_input_rel_uses_manifest_dir() {
+        let numbers = read_input_rel::<Numbers>("test_data/numbers.txt").unwrap();
+
+        assert_eq!(vec![1, 2, 3], numbers.values);
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct Seeds(Vec<u64>);
+
+    impl FromStr for Seeds {
+        type Err = PuzzleParseError;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            let values = s.strip_prefix("seeds:").ok_or(PuzzleParseError {msg: String::from("Missing seeds")})?;
+            let values = values.split_whitespace().map(|v| v.parse().map_err(|_| PuzzleParseError {msg: format!("Invalid seed: {v}")})).collect::<Result<_, _>>()?;
+            Ok(Seeds(values))
+        }
+    }
+
+    #[test]
+    fn read_two_parts_works() {
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/test_data/two_parts.txt");
+
+        let (seeds, numbers) = read_two_parts::<Seeds, Numbers>(path).unwrap();
+
+        assert_eq!(Seeds(vec![79, 14, 55, 13]), seeds);
+        assert_eq!(vec![1, 2, 3], numbers.values);
+    }
+
+    #[test]
+    fn read_two_parts_fails_on_invalid_input() {
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/test_data/two_parts.txt");
+
+        // The parts are not swapped
+        assert!(read_two_parts::<Numbers, Seeds>(path).is_err());
+
+        // The file does not exist
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/test_data/missing.txt");
+        assert!(read_two_parts::<Seeds, Numbers>(path).is_err());
+    }
+
+    #[cfg(feature = "gzip")]
+    #[test]
+    fn read_puzzle_input_gz_works() {
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/test_data/example.txt.gz");
+
+        let numbers = read_puzzle_input_gz::<Numbers>(path).unwrap();
+
+        assert_eq!(vec![1, 2, 3], numbers.values);
+    }
+
+    #[cfg(feature = "gzip")]
+    #[test]
+    fn read_puzzle_input_gz_fails_on_missing_file() {
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/test_data/missing.txt.gz");
+
+        assert!(read_puzzle_input_gz::<Numbers>(path).is_err());
+    }
+}
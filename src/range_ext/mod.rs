@@ -0,0 +1,89 @@
+use std::ops::RangeInclusive;
+
+/// Set operations on inclusive ranges, e.g. the section assignments of AoC 2022 Day 4 or the seed ranges of AoC 2023 Day 5.
+pub trait RangeExt<T> {
+    /// Return `true` if `self` and `other` have at least one value in common.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use aoc_helper::range_ext::RangeExt;
+    ///
+    /// assert!((2..=6).overlaps(&(6..=8)));
+    /// assert!(!(2..=4).overlaps(&(6..=8)));
+    /// ```
+    fn overlaps(&self, other: &Self) -> bool;
+
+    /// Return `true` if every value in `other` is also in `self`.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use aoc_helper::range_ext::RangeExt;
+    ///
+    /// assert!((2..=8).contains_range(&(3..=7)));
+    /// assert!(!(3..=7).contains_range(&(2..=8)));
+    /// ```
+    fn contains_range(&self, other: &Self) -> bool;
+
+    /// Return the range of values that are in both `self` and `other`, or [`None`] if the ranges do not overlap.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use aoc_helper::range_ext::RangeExt;
+    ///
+    /// assert_eq!((5..=7).intersection(&(7..=9)), Some(7..=7));
+    /// assert_eq!((2..=4).intersection(&(6..=8)), None);
+    /// ```
+    fn intersection(&self, other: &Self) -> Option<Self>
+    where
+        Self: Sized;
+}
+
+impl<T: Ord + Copy> RangeExt<T> for RangeInclusive<T> {
+    fn overlaps(&self, other: &Self) -> bool {
+        self.start() <= other.end() && other.start() <= self.end()
+    }
+
+    fn contains_range(&self, other: &Self) -> bool {
+        self.start() <= other.start() && other.end() <= self.end()
+    }
+
+    fn intersection(&self, other: &Self) -> Option<Self> {
+        if self.overlaps(other) {
+            Some(*self.start().max(other.start())..=*self.end().min(other.end()))
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn overlaps_works() {
+        assert!((5..=7).overlaps(&(7..=9)));
+        assert!((2..=8).overlaps(&(3..=7)));
+        assert!((3..=7).overlaps(&(2..=8)));
+        assert!(!(2..=3).overlaps(&(4..=5)));
+        assert!(!(4..=5).overlaps(&(2..=3)));
+    }
+
+    #[test]
+    fn contains_range_works() {
+        assert!((2..=8).contains_range(&(3..=7)));
+        assert!((6..=6).contains_range(&(6..=6)));
+        assert!(!(4..=6).contains_range(&(6..=7)));
+    }
+
+    #[test]
+    fn intersection_works() {
+        assert_eq!((2..=8).intersection(&(3..=7)), Some(3..=7));
+        assert_eq!((2..=6).intersection(&(4..=8)), Some(4..=6));
+        assert_eq!((-5..=-1).intersection(&(-3..=10)), Some(-3..=-1));
+        assert_eq!((2..=3).intersection(&(4..=5)), None);
+    }
+}
@@ -0,0 +1,311 @@
+use std::{collections::HashMap, hash::Hash};
+
+use priority_queue::DoublePriorityQueue;
+
+use crate::{
+    direction::{relative_direction::RelativeDirection, Direction},
+    geometry::point::Point2D,
+    graph::{grid::Grid, reconstruct_path, Graph, NodeIndex},
+};
+
+/// Search an implicit graph of states for the cheapest path from `start` to a goal state, using the A* algorithm.
+/// The states are generated on demand by `neighbors`, so there is no need to build a [`Graph`](crate::graph::Graph) containing every state up front.
+/// This is useful when the state contains more than a position, e.g. the position, direction and number of straight steps of the crucibles in AoC 2023 Day 17.
+///
+/// Returns the states on the path (including `start` and the goal), together with the total cost, or [`None`] if no goal state can be reached.
+///
+/// # Arguments
+///
+/// * `start` - The state where the search starts.
+/// * `neighbors` - A closure that returns the states that can be reached from a state in one move.
+/// * `heuristic` - A closure that estimates the remaining cost from a state to the closest goal. It must never overestimate the cost, otherwise the result may not be optimal. A heuristic that always returns 0 turns the search into Dijkstra's algorithm.
+/// * `cost` - A closure that returns the cost of moving from the first state to the second one.
+/// * `is_goal` - A closure that decides if a state is a goal.
+///
+/// # Example
+///
+/// ```
+/// use aoc_helper::search::search_states;
+///
+/// // Reach 10 from 1, where each move either adds 1 (cost 1) or doubles (cost 2)
+/// let (path, cost) = search_states(
+///     1_u32,
+///     |&n| vec![n + 1, n * 2],
+///     |_| 0,
+///     |&from, &to| if to == from + 1 { 1 } else { 2 },
+///     |&n| n == 10,
+/// )
+/// .unwrap();
+///
+/// assert_eq!(path, vec![1, 2, 4, 5, 10]);
+/// assert_eq!(cost, 6);
+/// ```
+pub fn search_states<S, FN, FH, FC, FT>(
+    start: S,
+    neighbors: FN,
+    heuristic: FH,
+    cost: FC,
+    is_goal: FT,
+) -> Option<(Vec<S>, usize)>
+where
+    S: Eq + Hash + Clone,
+    FN: Fn(&S) -> Vec<S>,
+    FH: Fn(&S) -> usize,
+    FC: Fn(&S, &S) -> usize,
+    FT: Fn(&S) -> bool,
+{
+    let mut frontier = DoublePriorityQueue::new();
+    frontier.push(start.clone(), heuristic(&start));
+
+    let mut came_from = HashMap::new();
+    came_from.insert(start.clone(), start.clone());
+
+    let mut cost_so_far = HashMap::new();
+    cost_so_far.insert(start.clone(), 0);
+
+    while let Some((current, _)) = frontier.pop_min() {
+        if is_goal(&current) {
+            let total = cost_so_far[&current];
+            return Some((reconstruct_path(&came_from, start, current), total));
+        }
+
+        for next in neighbors(&current) {
+            let new_cost = cost(&current, &next).saturating_add(cost_so_far[&current]);
+
+            if !cost_so_far.contains_key(&next) || new_cost < cost_so_far[&next] {
+                cost_so_far.insert(next.clone(), new_cost);
+                came_from.insert(next.clone(), current.clone());
+                frontier.push(next.clone(), new_cost.saturating_add(heuristic(&next)));
+            }
+        }
+    }
+
+    None
+}
+
+/// Find the cheapest path between two cells of a grid, where the path has to move in straight runs of limited length, like the crucibles in AoC 2023 Day 17.
+/// A run is the number of consecutive steps taken in the same direction. Every run has to be at least `min_run` and at most `max_run` steps long before the path can turn (or stop at `target`).
+/// The path can never reverse direction.
+///
+/// Returns the cells on the path (including `start` and `target`) and the total cost, or [`None`] if `target` cannot be reached.
+///
+/// # Arguments
+///
+/// * `grid` - The grid to search.
+/// * `start` - The cell where the path starts. The first run can go in any direction.
+/// * `target` - The cell where the path ends.
+/// * `min_run` - The minimum number of steps in a direction before the path can turn or stop.
+/// * `max_run` - The maximum number of steps in a direction before the path has to turn.
+/// * `cost_fn` - A function that calculates the cost of entering a cell given its data.
+///
+/// # Example
+///
+/// ```
+/// use aoc_helper::{graph::grid::Grid, search::grid_path_with_run_constraints};
+///
+/// let grid = Grid::new_from_data(vec![vec![1, 1, 1, 1], vec![9, 9, 9, 1]]);
+/// let (start, target) = (grid.first_index().unwrap(), grid.last_index().unwrap());
+///
+/// // At most 2 steps in a straight line, so the path has to zigzag through the expensive row
+/// let (_, cost) = grid_path_with_run_constraints(&grid, start, target, 1, 2, |&v| v).unwrap();
+/// assert_eq!(cost, 12);
+///
+/// let (path, cost) = grid_path_with_run_constraints(&grid, start, target, 1, 3, |&v| v).unwrap();
+/// assert_eq!(cost, 4);
+/// assert_eq!(path.len(), 5);
+/// ```
+pub fn grid_path_with_run_constraints<T, F>(
+    grid: &Grid<T>,
+    start: NodeIndex,
+    target: NodeIndex,
+    min_run: usize,
+    max_run: usize,
+    cost_fn: F,
+) -> Option<(Vec<NodeIndex>, usize)>
+where
+    T: Clone,
+    F: Fn(&T) -> usize,
+{
+    let indices = grid.node_indices.as_deref()?;
+
+    let step = |node: NodeIndex, dir: RelativeDirection| {
+        let (row, col) = grid.coords_of(node)?;
+        let pos = Point2D::new(col, row).checked_add_offset(dir.get_offset())?;
+        indices.get(pos.y)?.get(pos.x).cloned()
+    };
+
+    let neighbors = |&(node, dir, run): &(NodeIndex, Option<RelativeDirection>, usize)| {
+        RelativeDirection::all()
+            .into_iter()
+            .filter(|&d| match dir {
+                None => true,
+                Some(current) if d == current => run < max_run,
+                Some(current) => run >= min_run && d != current.get_opposite(),
+            })
+            .filter_map(|d| {
+                let run = if dir == Some(d) { run + 1 } else { 1 };
+                step(node, d).map(|next| (next, Some(d), run))
+            })
+            .collect()
+    };
+
+    let cost = |_: &_, &(node, _, _): &(NodeIndex, Option<RelativeDirection>, usize)| {
+        cost_fn(grid.get_data(&node).unwrap())
+    };
+
+    let is_goal = |&(node, dir, run): &(NodeIndex, Option<RelativeDirection>, usize)| {
+        node == target && (dir.is_none() || run >= min_run)
+    };
+
+    search_states((start, None, 0), neighbors, |_| 0, cost, is_goal)
+        .map(|(path, total)| (path.into_iter().map(|(node, _, _)| node).collect(), total))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const DAY_17_EXAMPLE: &str = "2413432311323
+3215453535623
+3255245654254
+3446585845452
+4546657867536
+1438598798454
+4457876987766
+3637877979653
+4654967986887
+4564679986453
+1224686865563
+2546548887735
+4322674655533";
+
+    #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+    struct Crucible {
+        pos: Point2D<usize>,
+        dir: Option<RelativeDirection>,
+        steps: usize,
+    }
+
+    #[test]
+    fn search_states_solves_2023_day_17() {
+        let heat_loss = DAY_17_EXAMPLE
+            .lines()
+            .map(|l| {
+                l.chars()
+                    .map(|c| c.to_digit(10).unwrap() as usize)
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        let target = Point2D::new(heat_loss[0].len() - 1, heat_loss.len() - 1);
+        let start = Crucible {
+            pos: Point2D::new(0, 0),
+            dir: None,
+            steps: 0,
+        };
+
+        let neighbors = |c: &Crucible| {
+            RelativeDirection::all()
+                .into_iter()
+                .filter(|&d| c.dir.is_none_or(|dir| d != dir.get_opposite()))
+                .filter(|&d| c.dir != Some(d) || c.steps < 3)
+                .filter_map(|d| {
+                    let pos = c.pos.checked_add_offset(d.get_offset())?;
+                    if pos.x > target.x || pos.y > target.y {
+                        return None;
+                    }
+
+                    let steps = if c.dir == Some(d) { c.steps + 1 } else { 1 };
+                    Some(Crucible {
+                        pos,
+                        dir: Some(d),
+                        steps,
+                    })
+                })
+                .collect()
+        };
+        let heuristic = |c: &Crucible| target.x - c.pos.x + target.y - c.pos.y;
+        let cost = |_: &Crucible, to: &Crucible| heat_loss[to.pos.y][to.pos.x];
+        let is_goal = |c: &Crucible| c.pos == target;
+
+        let (path, total) = search_states(start, neighbors, heuristic, cost, is_goal).unwrap();
+
+        assert_eq!(total, 102);
+        assert_eq!(path.first().unwrap().pos, Point2D::new(0, 0));
+        assert_eq!(path.last().unwrap().pos, target);
+        assert!(path.iter().all(|c| c.steps <= 3));
+    }
+
+    fn day_17_grid(input: &str) -> Grid<usize> {
+        Grid::new_from_data(
+            input
+                .lines()
+                .map(|l| {
+                    l.chars()
+                        .map(|c| c.to_digit(10).unwrap() as usize)
+                        .collect()
+                })
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn grid_path_with_run_constraints_solves_2023_day_17() {
+        let grid = day_17_grid(DAY_17_EXAMPLE);
+        let (start, target) = (grid.first_index().unwrap(), grid.last_index().unwrap());
+
+        let (path, cost) =
+            grid_path_with_run_constraints(&grid, start, target, 1, 3, |&v| v).unwrap();
+        assert_eq!(cost, 102);
+        assert_eq!(path.first(), Some(&start));
+        assert_eq!(path.last(), Some(&target));
+
+        let (_, cost) =
+            grid_path_with_run_constraints(&grid, start, target, 4, 10, |&v| v).unwrap();
+        assert_eq!(cost, 94);
+    }
+
+    #[test]
+    fn grid_path_with_run_constraints_requires_min_run_before_stopping() {
+        let grid = day_17_grid(
+            "111111111111
+999999999991
+999999999991
+999999999991
+999999999991",
+        );
+        let (start, target) = (grid.first_index().unwrap(), grid.last_index().unwrap());
+
+        let (_, cost) =
+            grid_path_with_run_constraints(&grid, start, target, 4, 10, |&v| v).unwrap();
+        assert_eq!(cost, 71);
+    }
+
+    #[test]
+    fn grid_path_with_run_constraints_returns_none_if_runs_do_not_fit() {
+        let grid = day_17_grid("111\n111");
+        let (start, target) = (grid.first_index().unwrap(), grid.last_index().unwrap());
+
+        assert!(grid_path_with_run_constraints(&grid, start, target, 4, 10, |&v| v).is_none());
+    }
+
+    #[test]
+    fn search_states_returns_none_if_goal_is_unreachable() {
+        let result = search_states(
+            0_i32,
+            |&n| if n < 5 { vec![n + 1] } else { vec![] },
+            |_| 0,
+            |_, _| 1,
+            |&n| n == 10,
+        );
+
+        assert!(result.is_none());
+    }
+
+    #[test]
+    fn search_states_start_can_be_goal() {
+        let result = search_states('a', |_| vec!['b'], |_| 0, |_, _| 1, |&c| c == 'a');
+
+        assert_eq!(result, Some((vec!['a'], 0)));
+    }
+}
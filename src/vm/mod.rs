@@ -0,0 +1,185 @@
+use std::collections::HashMap;
+
+/// Decides where a [`Machine`] (or an [`Executor`](crate::parse::instruction::Executor)) continues after executing an instruction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ControlFlow {
+    /// Continue with the next instruction.
+    Next,
+    /// Jump by the given offset, relative to the current instruction.
+    Jump(isize),
+    /// Stop running the program.
+    Halt,
+}
+
+/// A simple register machine, that can be used as a starting point for the assembly-style puzzles of AoC (e.g. AoC 2016 Day 12).
+/// The machine only keeps track of its registers and its instruction pointer, the meaning of each instruction is decided by the closure passed to [`Machine::run`].
+///
+/// # Example:
+///
+/// ```
+/// use aoc_helper::{
+///     parse::{instruction::Instruction, parse_instructions},
+///     vm::{ControlFlow, Machine},
+/// };
+///
+/// let program = parse_instructions("set a 3\nmul a 7", Instruction::parse);
+/// let mut machine = Machine::new();
+///
+/// machine.run(&program, |m, i| {
+///     match i.op.as_str() {
+///         "set" => m.set(&i.args[0], m.value(&i.args[1])),
+///         "mul" => m.set(&i.args[0], m.get(&i.args[0]) * m.value(&i.args[1])),
+///         _ => unreachable!(),
+///     }
+///     ControlFlow::Next
+/// });
+///
+/// assert_eq!(machine.get("a"), 21);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Machine {
+    /// The values of the registers, by name. Registers that were never set are not included.
+    pub registers: HashMap<String, i64>,
+    /// The index of the next instruction to execute.
+    pub ip: usize,
+}
+
+impl Machine {
+    /// Creates a new [`Machine`], with no registers set, and the instruction pointer at the first instruction.
+    pub fn new() -> Machine {
+        Machine::default()
+    }
+
+    /// Return the value of the register called `name`. Registers that were never set have a value of 0.
+    pub fn get(&self, name: &str) -> i64 {
+        self.registers.get(name).copied().unwrap_or(0)
+    }
+
+    /// Set the value of the register called `name` to `value`.
+    pub fn set(&mut self, name: &str, value: i64) {
+        match self.registers.get_mut(name) {
+            Some(register) => *register = value,
+            None => {
+                self.registers.insert(name.to_string(), value);
+            }
+        }
+    }
+
+    /// Return the value of an instruction argument, that is either an integer literal, or the name of a register.
+    pub fn value(&self, arg: &str) -> i64 {
+        arg.parse().unwrap_or_else(|_| self.get(arg))
+    }
+
+    /// Run `program` from the current instruction pointer, until the instruction pointer leaves the program, or `step` returns [`ControlFlow::Halt`].
+    /// `step` is called with the machine and the current instruction, and decides which instruction comes next.
+    /// Returns the number of instructions that were executed. Does not return if the program never terminates.
+    ///
+    /// # Arguments
+    ///
+    /// * `program` - The instructions to execute.
+    /// * `step` - A closure that executes a single instruction.
+    pub fn run<I, F>(&mut self, program: &[I], mut step: F) -> usize
+    where
+        F: FnMut(&mut Machine, &I) -> ControlFlow,
+    {
+        let mut ip = self.ip;
+
+        let steps = drive(program, &mut ip, |current, instruction| {
+            self.ip = current;
+            step(self, instruction)
+        });
+
+        self.ip = ip;
+        steps
+    }
+}
+
+/// Move the instruction pointer `ip` through `program`, until it leaves the program, or `step` returns [`ControlFlow::Halt`].
+/// `step` is called with the current instruction pointer and instruction. On a halt, `ip` is left at the instruction that halted.
+/// Returns the number of instructions that were executed. This is the loop behind both [`Machine::run`] and [`Executor::run`](crate::parse::instruction::Executor::run).
+pub(crate) fn drive<I, F>(program: &[I], ip: &mut usize, mut step: F) -> usize
+where
+    F: FnMut(usize, &I) -> ControlFlow,
+{
+    let mut steps = 0;
+
+    while let Some(instruction) = program.get(*ip) {
+        steps += 1;
+
+        let next = match step(*ip, instruction) {
+            ControlFlow::Next => ip.checked_add(1),
+            ControlFlow::Jump(offset) => ip.checked_add_signed(offset),
+            ControlFlow::Halt => break,
+        };
+
+        match next {
+            Some(next) => *ip = next,
+            None => break,
+        }
+    }
+
+    steps
+}
+
+#[cfg(test)]
+mod test {
+    use crate::parse::{instruction::Instruction, parse_instructions};
+
+    use super::*;
+
+    fn execute(machine: &mut Machine, instruction: &Instruction) -> ControlFlow {
+        let args = &instruction.args;
+
+        match instruction.op.as_str() {
+            "cpy" => machine.set(&args[1], machine.value(&args[0])),
+            "inc" => machine.set(&args[0], machine.get(&args[0]) + 1),
+            "dec" => machine.set(&args[0], machine.get(&args[0]) - 1),
+            "jnz" if machine.value(&args[0]) != 0 => {
+                return ControlFlow::Jump(machine.value(&args[1]) as isize)
+            }
+            "jnz" => {}
+            "hlt" => return ControlFlow::Halt,
+            _ => panic!("Unknown instruction: {}", instruction.op),
+        }
+
+        ControlFlow::Next
+    }
+
+    #[test]
+    fn run_increment_program() {
+        // Add b to a, by incrementing a and decrementing b until b is 0
+        let program = parse_instructions(
+            "cpy 2 a
+cpy 5 b
+inc a
+dec b
+jnz b -2",
+            Instruction::parse,
+        );
+        let mut machine = Machine::new();
+
+        let steps = machine.run(&program, execute);
+
+        assert_eq!(machine.get("a"), 7);
+        assert_eq!(machine.get("b"), 0);
+        assert_eq!(machine.get("c"), 0);
+        assert_eq!(machine.ip, 5);
+        assert_eq!(steps, 2 + 5 * 3);
+    }
+
+    #[test]
+    fn run_stops_on_halt_and_negative_jumps() {
+        let program = parse_instructions("inc a\nhlt\ninc a", Instruction::parse);
+        let mut machine = Machine::new();
+
+        assert_eq!(machine.run(&program, execute), 2);
+        assert_eq!(machine.get("a"), 1);
+        assert_eq!(machine.ip, 1);
+
+        let program = parse_instructions("jnz 1 -5\ninc a", Instruction::parse);
+        let mut machine = Machine::new();
+
+        assert_eq!(machine.run(&program, execute), 1);
+        assert_eq!(machine.get("a"), 0);
+    }
+}